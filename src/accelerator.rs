@@ -0,0 +1,416 @@
+//! Transaction acceleration (Accelerator) API
+
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use bitcoin::{Amount, BlockHash, FeeRate, Txid};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use url::Url;
+
+use crate::client::MempoolClient;
+use crate::deser;
+use crate::error::Error;
+
+/// Acceleration status
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccelerationStatus {
+    /// Acceleration requested, not yet sent to the mining pools
+    Requested,
+    /// Being accelerated by the mining pools
+    Accelerating,
+    /// Mined by an accelerating pool, waiting for confirmations
+    Mined,
+    /// Mined by an accelerating pool
+    Completed,
+    /// Mined by an accelerating pool, not yet settled
+    CompletedProvisional,
+    /// Mined by a pool not accelerating it, or expired
+    Failed,
+    /// Mined by a pool not accelerating it, not yet settled
+    FailedProvisional,
+    /// Any other status
+    Other(String),
+}
+
+impl AccelerationStatus {
+    /// Get the status as returned by the API
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Requested => "requested",
+            Self::Accelerating => "accelerating",
+            Self::Mined => "mined",
+            Self::Completed => "completed",
+            Self::CompletedProvisional => "completed_provisional",
+            Self::Failed => "failed",
+            Self::FailedProvisional => "failed_provisional",
+            Self::Other(status) => status,
+        }
+    }
+}
+
+impl fmt::Display for AccelerationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AccelerationStatus {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "requested" => Self::Requested,
+            "accelerating" => Self::Accelerating,
+            "mined" => Self::Mined,
+            "completed" => Self::Completed,
+            "completed_provisional" => Self::CompletedProvisional,
+            "failed" => Self::Failed,
+            "failed_provisional" => Self::FailedProvisional,
+            status => Self::Other(status.to_string()),
+        })
+    }
+}
+
+impl Serialize for AccelerationStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for AccelerationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let status: String = String::deserialize(deserializer)?;
+        let Ok(status) = Self::from_str(&status);
+        Ok(status)
+    }
+}
+
+/// Summary of the transaction (and its unconfirmed ancestors) to accelerate
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AccelerationTxSummary {
+    /// Transaction ID
+    pub txid: Txid,
+    /// Virtual size of the transaction and its unconfirmed ancestors
+    #[serde(rename = "effectiveVsize")]
+    pub effective_vsize: f64,
+    /// Fee of the transaction and its unconfirmed ancestors
+    #[serde(rename = "effectiveFee")]
+    pub effective_fee: Amount,
+    /// Number of unconfirmed ancestors
+    #[serde(rename = "ancestorCount")]
+    pub ancestor_count: u32,
+}
+
+/// Accepted payment method
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AccelerationPaymentMethod {
+    /// Whether the payment method is available
+    pub enabled: bool,
+    /// Minimum payment, in the smallest unit of the payment method
+    pub min: u64,
+    /// Maximum payment, in the smallest unit of the payment method
+    pub max: u64,
+}
+
+/// Acceleration option
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AccelerationOption {
+    /// Maximum bid
+    pub fee: Amount,
+}
+
+/// Acceleration cost estimate
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AccelerationEstimate {
+    /// Transaction summary
+    #[serde(rename = "txSummary")]
+    pub tx_summary: AccelerationTxSummary,
+    /// Additional fee needed to reach the target fee rate
+    pub cost: Amount,
+    /// Target fee rate
+    #[serde(rename = "targetFeeRate")]
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub target_fee_rate: FeeRate,
+    /// Fee needed to be included in the next block
+    #[serde(rename = "nextBlockFee")]
+    pub next_block_fee: Amount,
+    /// Balance of the authenticated user
+    #[serde(rename = "userBalance")]
+    #[serde(default)]
+    pub user_balance: Amount,
+    /// Flat fee of the accelerator
+    #[serde(rename = "mempoolBaseFee")]
+    pub mempool_base_fee: Amount,
+    /// Fee proportional to the transaction size
+    #[serde(rename = "vsizeFee")]
+    pub vsize_fee: Amount,
+    /// IDs of the mining pools accelerating transactions
+    #[serde(default)]
+    pub pools: Vec<u32>,
+    /// Bid options
+    #[serde(default)]
+    pub options: Vec<AccelerationOption>,
+    /// Whether the user has access to the accelerator
+    #[serde(rename = "hasAccess")]
+    #[serde(default)]
+    pub has_access: bool,
+    /// Accepted payment methods
+    #[serde(rename = "availablePaymentMethods")]
+    #[serde(default)]
+    pub available_payment_methods: BTreeMap<String, AccelerationPaymentMethod>,
+    /// Whether acceleration is currently unavailable
+    #[serde(default)]
+    pub unavailable: bool,
+}
+
+impl AccelerationEstimate {
+    /// Total cost of the acceleration, excluding the bid (cost + base fee + vsize fee)
+    pub fn total_cost(&self) -> Amount {
+        self.cost + self.mempool_base_fee + self.vsize_fee
+    }
+}
+
+/// Pending acceleration
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Acceleration {
+    /// Transaction ID
+    pub txid: Txid,
+    /// UNIX timestamp of the request
+    pub added: u64,
+    /// Fee added by the accelerating pools
+    #[serde(rename = "feeDelta")]
+    pub fee_delta: Amount,
+    /// Virtual size of the transaction and its unconfirmed ancestors
+    #[serde(rename = "effectiveVsize")]
+    pub effective_vsize: f64,
+    /// Fee of the transaction and its unconfirmed ancestors
+    #[serde(rename = "effectiveFee")]
+    pub effective_fee: Amount,
+    /// IDs of the accelerating mining pools
+    #[serde(default)]
+    pub pools: Vec<u32>,
+}
+
+/// Acceleration history entry
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AccelerationHistoryEntry {
+    /// Transaction ID
+    pub txid: Txid,
+    /// Status
+    pub status: AccelerationStatus,
+    /// UNIX timestamp of the request
+    pub added: u64,
+    /// UNIX timestamp of the last status update
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: u64,
+    /// Fee added by the accelerating pools
+    #[serde(rename = "feeDelta")]
+    pub fee_delta: Amount,
+    /// Virtual size of the transaction and its unconfirmed ancestors
+    #[serde(rename = "effectiveVsize")]
+    pub effective_vsize: f64,
+    /// Fee of the transaction and its unconfirmed ancestors
+    #[serde(rename = "effectiveFee")]
+    pub effective_fee: Amount,
+    /// Fee paid for the acceleration
+    #[serde(rename = "feePaid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_paid: Option<Amount>,
+    /// Bid boost actually paid to the pools
+    #[serde(rename = "bidBoost")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_boost: Option<Amount>,
+    /// Hash of the block including the transaction
+    #[serde(rename = "blockHash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<BlockHash>,
+    /// Height of the block including the transaction
+    #[serde(rename = "blockHeight")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_height: Option<u32>,
+    /// IDs of the accelerating mining pools
+    #[serde(default)]
+    pub pools: Vec<u32>,
+}
+
+/// Accelerator client
+///
+/// Construct it with [`MempoolClient::accelerator`].
+#[derive(Debug, Clone, Copy)]
+pub struct AcceleratorClient<'a> {
+    client: &'a MempoolClient,
+}
+
+impl MempoolClient {
+    /// Get the accelerator client
+    #[inline]
+    pub fn accelerator(&self) -> AcceleratorClient<'_> {
+        AcceleratorClient { client: self }
+    }
+}
+
+impl AcceleratorClient<'_> {
+    /// Estimate the cost of accelerating a transaction.
+    pub async fn estimate(&self, txid: Txid) -> Result<AccelerationEstimate, Error> {
+        #[derive(Serialize)]
+        struct EstimateRequest {
+            #[serde(rename = "txInput")]
+            tx_input: Txid,
+        }
+
+        let url: Url = self
+            .client
+            .endpoint("/api/v1/services/accelerator/estimate")?;
+        self.client
+            .post_json(url, &EstimateRequest { tx_input: txid })
+            .await
+    }
+
+    /// Get the pending accelerations.
+    pub async fn get_accelerations(&self) -> Result<Vec<Acceleration>, Error> {
+        let url: Url = self
+            .client
+            .endpoint("/api/v1/services/accelerator/accelerations")?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the acceleration history.
+    ///
+    /// If `status` is specified, only the accelerations with that status are returned.
+    /// If `block_hash` is specified, only the accelerations mined in that block are returned.
+    pub async fn get_acceleration_history(
+        &self,
+        status: Option<AccelerationStatus>,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<AccelerationHistoryEntry>, Error> {
+        let mut url: Url = self
+            .client
+            .endpoint("/api/v1/services/accelerator/accelerations/history")?;
+
+        {
+            let mut query = url.query_pairs_mut();
+
+            if let Some(status) = status {
+                query.append_pair("status", status.as_str());
+            }
+
+            if let Some(block_hash) = block_hash {
+                query.append_pair("blockHash", &block_hash.to_string());
+            }
+        }
+
+        self.client.get_response(url).await
+    }
+
+    /// Get the status of the acceleration of a transaction.
+    ///
+    /// The status is taken from the recent acceleration history. The pending accelerations
+    /// don't report a status, so a pending transaction missing from the history is
+    /// assumed to be [`AccelerationStatus::Accelerating`].
+    ///
+    /// Returns `None` if the transaction is neither in the recent history nor pending.
+    pub async fn get_acceleration_status(
+        &self,
+        txid: Txid,
+    ) -> Result<Option<AccelerationStatus>, Error> {
+        let history: Vec<AccelerationHistoryEntry> =
+            self.get_acceleration_history(None, None).await?;
+
+        if let Some(entry) = history.into_iter().find(|entry| entry.txid == txid) {
+            return Ok(Some(entry.status));
+        }
+
+        let pending: Vec<Acceleration> = self.get_accelerations().await?;
+
+        Ok(pending
+            .iter()
+            .any(|acc| acc.txid == txid)
+            .then_some(AccelerationStatus::Accelerating))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_acceleration_estimate_deserialization() {
+        let json = r#"{"txSummary":{"txid":"ee13ebb99632377c15c94980357f674d285ac413452050031ea6dcd3e9b2dc29","effectiveVsize":154,"effectiveFee":154,"ancestorCount":1},"cost":1386,"targetFeeRate":10,"nextBlockFee":1540,"userBalance":0,"mempoolBaseFee":50000,"vsizeFee":0,"pools":[111,102,115],"options":[{"fee":1500},{"fee":3000},{"fee":12500}],"hasAccess":false,"availablePaymentMethods":{"bitcoin":{"enabled":true,"min":1000,"max":10000000},"cashapp":{"enabled":true,"min":10,"max":200}},"unavailable":false}"#;
+
+        let estimate: AccelerationEstimate = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            estimate.tx_summary.txid,
+            Txid::from_str("ee13ebb99632377c15c94980357f674d285ac413452050031ea6dcd3e9b2dc29")
+                .unwrap()
+        );
+        assert_eq!(estimate.tx_summary.effective_fee, Amount::from_sat(154));
+        assert_eq!(estimate.cost, Amount::from_sat(1386));
+        assert_eq!(
+            estimate.target_fee_rate,
+            FeeRate::from_sat_per_vb_unchecked(10)
+        );
+        assert_eq!(estimate.next_block_fee, Amount::from_sat(1540));
+        assert_eq!(estimate.total_cost(), Amount::from_sat(51386));
+        assert_eq!(estimate.pools, vec![111, 102, 115]);
+        assert_eq!(estimate.options[2].fee, Amount::from_sat(12500));
+        assert!(estimate.available_payment_methods["bitcoin"].enabled);
+        assert!(!estimate.unavailable);
+    }
+
+    #[test]
+    fn test_accelerations_deserialization() {
+        let json = r#"[{"txid":"d7e1796d8eb4a09d4e6c174e36cfd852f1e6e6c9f7df4496339fec7d8f3b0b8c","added":1707924108,"feeDelta":3500,"effectiveVsize":111,"effectiveFee":500,"pools":[111]}]"#;
+
+        let accelerations: Vec<Acceleration> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(accelerations.len(), 1);
+        assert_eq!(accelerations[0].added, 1707924108);
+        assert_eq!(accelerations[0].fee_delta, Amount::from_sat(3500));
+        assert_eq!(accelerations[0].effective_vsize, 111.0);
+    }
+
+    #[test]
+    fn test_acceleration_history_deserialization() {
+        let json = r#"[{"txid":"7ddf5b2ab6ed6bbfbd2a31ea9fde0c04bc5fdfa42d4e0ea7faf0b1f1d4de1f3c","status":"completed","added":1713569981,"lastUpdated":1713571767,"effectiveFee":1000,"effectiveVsize":100.25,"feeDelta":6000,"feePaid":10000,"bidBoost":6102,"blockHash":"0000000000000000000320283a032748cef8227873ff4872689bf23f1cda83a5","blockHeight":840000,"pools":[111]},{"txid":"f5864806e3565c34d1b41e716f72609d00b55ea5eac5b924c9719a842ef42206","status":"accelerating","added":1707926000,"lastUpdated":1707926000,"effectiveFee":200,"effectiveVsize":141,"feeDelta":1800,"pools":[111,102]}]"#;
+
+        let history: Vec<AccelerationHistoryEntry> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].status, AccelerationStatus::Completed);
+        assert_eq!(history[0].fee_paid, Some(Amount::from_sat(10000)));
+        assert_eq!(history[0].block_height, Some(840000));
+        assert_eq!(history[0].effective_vsize, 100.25);
+        assert_eq!(history[1].status, AccelerationStatus::Accelerating);
+        assert_eq!(history[1].block_hash, None);
+        assert_eq!(history[1].fee_paid, None);
+
+        // Round-trip
+        let serialized = serde_json::to_string(&history).unwrap();
+        let deserialized: Vec<AccelerationHistoryEntry> =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, history);
+    }
+
+    #[test]
+    fn test_acceleration_status() {
+        let status: AccelerationStatus =
+            serde_json::from_str(r#""completed_provisional""#).unwrap();
+        assert_eq!(status, AccelerationStatus::CompletedProvisional);
+
+        // Unknown statuses are kept
+        let status: AccelerationStatus = serde_json::from_str(r#""cancelled""#).unwrap();
+        assert_eq!(status, AccelerationStatus::Other(String::from("cancelled")));
+        assert_eq!(serde_json::to_string(&status).unwrap(), r#""cancelled""#);
+    }
+}
@@ -4,6 +4,7 @@
 use std::net::SocketAddr;
 use std::time::Duration;
 
+use bitcoin::Network;
 #[cfg(feature = "socks")]
 use reqwest::Proxy;
 use reqwest::{Client, ClientBuilder};
@@ -11,14 +12,21 @@ use url::Url;
 
 use crate::client::MempoolClient;
 use crate::error::Error;
+use crate::retry::RetryPolicy;
 
 /// Mempool client builder
 #[derive(Debug, Clone)]
 pub struct MempoolClientBuilder {
     /// Endpoint URL
     pub url: Url,
+    /// Network
+    pub network: Network,
+    /// Custom network path prefix (default: the mempool.space one of [`MempoolClientBuilder::network`])
+    pub network_prefix: Option<String>,
     /// Timeout for requests
     pub timeout: Duration,
+    /// Retry policy
+    pub retry_policy: RetryPolicy,
     /// Socks5 proxy
     #[cfg(feature = "socks")]
     pub proxy: Option<SocketAddr>,
@@ -29,12 +37,42 @@ impl MempoolClientBuilder {
     pub fn new(url: Url) -> Self {
         Self {
             url,
+            network: Network::Bitcoin,
+            network_prefix: None,
             timeout: Duration::from_secs(60),
+            retry_policy: RetryPolicy::none(),
             #[cfg(feature = "socks")]
             proxy: None,
         }
     }
 
+    /// Set the network (default: [`Network::Bitcoin`])
+    ///
+    /// Requests are sent under the mempool.space path prefix of the network:
+    /// `/testnet`, `/testnet4` or `/signet` (e.g., `/signet/api/...`), and no prefix for mainnet and regtest.
+    /// The prefix is added for every base URL, including self-hosted instances;
+    /// use [`MempoolClientBuilder::network_prefix`] to override or disable it.
+    ///
+    /// Addresses of other networks are rejected with [`Error::NetworkMismatch`].
+    #[inline]
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Override the network path prefix (default: the mempool.space one of the network)
+    ///
+    /// Useful for self-hosted instances that serve a single network at the root of the base URL:
+    /// an empty `prefix` disables it. Address network validation is not affected.
+    #[inline]
+    pub fn network_prefix<S>(mut self, prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.network_prefix = Some(prefix.into());
+        self
+    }
+
     /// Set a custom timeout
     #[inline]
     pub fn timeout(mut self, timeout: Duration) -> Self {
@@ -42,6 +80,15 @@ impl MempoolClientBuilder {
         self
     }
 
+    /// Set the retry policy (default: [`RetryPolicy::none`])
+    ///
+    /// The policy applies to every request of the client.
+    #[inline]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Set proxy
     #[inline]
     #[cfg(feature = "socks")]
@@ -69,6 +116,11 @@ impl MempoolClientBuilder {
         let client: Client = builder.build()?;
 
         // Construct client
-        Ok(MempoolClient::from_client(self.url, client))
+        let mut client: MempoolClient = MempoolClient::from_client(self.url, client);
+        client.network = self.network;
+        client.network_prefix = self.network_prefix;
+        client.retry_policy = self.retry_policy;
+
+        Ok(client)
     }
 }
@@ -1,16 +1,33 @@
 //! Client
 
-use bitcoin::{Address, BlockHash};
-use reqwest::{Client, Response};
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::time::Duration;
+
+use bitcoin::block::Header;
+use bitcoin::hashes::{Hash, sha256};
+use bitcoin::hex::DisplayHex;
+use bitcoin::{Address, Block, BlockHash, Network, Script, Transaction, Txid, consensus};
+use futures_util::future;
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde::Serialize;
 use serde::de::DeserializeOwned;
-use url::Url;
+use url::{ParseError, Url};
 
 use crate::builder::MempoolClientBuilder;
-use crate::error::Error;
+use crate::error::{Error, TransactionRejection};
 use crate::response::{
-    AddressStats, BlockInfo, BlockInfoV1, DifficultyAdjustment, FeeRecommendations, HashrateStats,
-    MempoolBlockFees, MempoolResponse, MempoolStats, Prices,
+    AddressStats, BlockAuditEntry, BlockAuditSummary, BlockFeeRatesEntry, BlockFeesEntry,
+    BlockInfo, BlockInfoV1, BlockRewardsEntry, BlockSizesAndWeights, BlockStatus, BlockTimestamp,
+    CpfpInfo, Currency, DifficultyAdjustment, DifficultyAdjustmentResponse, DifficultyEntry,
+    FeeRecommendations, HashrateStats, HistoricalPrices, MempoolAcceptResult, MempoolBlockFees,
+    MempoolResponse, MempoolStats, MerkleProof, MiningPoolDetails, MiningPoolsRanking, Outspend,
+    OutspendResponse, PackageSubmission, PoolHashrateEntry, Prices, RbfHistory, RbfTree,
+    ScriptHashStats, StrippedTransaction, TransactionInfo, TransactionStatus, Utxo,
 };
+use crate::retry::RetryPolicy;
 #[cfg(feature = "ws")]
 use crate::websocket::{self, MempoolSubscription, MempoolSubscriptionRequest};
 
@@ -51,10 +68,43 @@ impl HashratePeriod {
     }
 }
 
+/// Number of transactions returned per page by `/api/block/:hash/txs/:start_index`
+const BLOCK_TXS_PAGE_SIZE: u32 = 25;
+
+/// Maximum number of txids accepted by `/api/v1/outspends`
+const OUTSPENDS_BATCH_SIZE: usize = 50;
+
+/// Cursor used to walk paginated confirmed transactions
+enum ChainTransactionsCursor {
+    Start,
+    LastSeen(Txid),
+    Done,
+}
+
+/// Compute the Electrum-style script hash (reversed SHA256 of the script, hex-encoded)
+fn electrum_script_hash(script: &Script) -> String {
+    let hash: sha256::Hash = sha256::Hash::hash(script.as_bytes());
+    let mut bytes: [u8; 32] = hash.to_byte_array();
+    bytes.reverse();
+    bytes.to_lower_hex_string()
+}
+
+/// Make sure that the path of the base URL ends with a slash, so that relative joins append to it.
+fn normalize_base_url(mut url: Url) -> Url {
+    if !url.path().ends_with('/') {
+        let path: String = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    url
+}
+
 /// Mempool Space client
 #[derive(Debug, Clone)]
 pub struct MempoolClient {
     url: Url,
+    pub(crate) network: Network,
+    pub(crate) network_prefix: Option<String>,
+    pub(crate) retry_policy: RetryPolicy,
     client: Client,
 }
 
@@ -82,52 +132,640 @@ impl MempoolClient {
     }
 
     /// Construct new with a custom reqwest [`Client`].
+    ///
+    /// The path of `url` (e.g., `https://example.com/mempool/`) is preserved as a prefix of all the endpoints.
+    /// The client targets [`Network::Bitcoin`] and never retries failed requests;
+    /// use [`MempoolClient::builder`] to change them.
     #[inline]
     pub fn from_client(url: Url, client: Client) -> Self {
-        Self { client, url }
+        Self {
+            client,
+            url: normalize_base_url(url),
+            network: Network::Bitcoin,
+            network_prefix: None,
+            retry_policy: RetryPolicy::none(),
+        }
+    }
+
+    /// Get the network the client targets
+    #[inline]
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Path prefix of the network (e.g., `/testnet` on mempool.space)
+    fn network_prefix(&self) -> &str {
+        if let Some(prefix) = &self.network_prefix {
+            return prefix.trim_end_matches('/');
+        }
+
+        match self.network {
+            Network::Testnet => "/testnet",
+            Network::Testnet4 => "/testnet4",
+            Network::Signet => "/signet",
+            // Mainnet is served at the root, and regtest only by self-hosted instances
+            _ => "",
+        }
+    }
+
+    /// Build the URL of an endpoint, ignoring the network prefix.
+    ///
+    /// `path` is resolved relative to the base URL, so that its path prefix is kept.
+    #[inline]
+    pub(crate) fn join_url(&self, path: &str) -> Result<Url, Error> {
+        Ok(self.url.join(path.trim_start_matches('/'))?)
+    }
+
+    /// Build the URL of an endpoint of the selected network.
+    pub(crate) fn endpoint(&self, path: &str) -> Result<Url, Error> {
+        let prefix: &str = self.network_prefix();
+        self.join_url(&format!("{prefix}{path}"))
     }
 
-    async fn get_response<T>(&self, url: Url) -> Result<T, Error>
+    /// Build the URL of an endpoint of the selected network, appending percent-encoded `segments` to `path`.
+    pub(crate) fn endpoint_with_segments(
+        &self,
+        path: &str,
+        segments: &[&str],
+    ) -> Result<Url, Error> {
+        let mut url: Url = self.endpoint(path)?;
+        url.path_segments_mut()
+            .map_err(|()| Error::Url(ParseError::RelativeUrlWithCannotBeABaseBase))?
+            .pop_if_empty()
+            .extend(segments.iter().map(|segment| match *segment {
+                // Dot segments would be resolved against the path. They can't be represented
+                // in a URL, so the escaped `%` makes them miss every endpoint instead.
+                "." => "%2E",
+                ".." => "%2E%2E",
+                segment => segment,
+            }));
+        Ok(url)
+    }
+
+    /// Check that an address is valid for the selected network.
+    fn check_address_network(&self, address: &Address) -> Result<(), Error> {
+        if address.as_unchecked().is_valid_for_network(self.network) {
+            Ok(())
+        } else {
+            Err(Error::NetworkMismatch {
+                expected: self.network,
+                address: address.to_string(),
+            })
+        }
+    }
+
+    /// Send a request, retrying it according to the retry policy.
+    ///
+    /// Non-`idempotent` requests (e.g., transaction submissions) may have been processed by the server
+    /// even if it replied with an error or timed out, so they are retried only on connection errors.
+    async fn send(&self, mut request: RequestBuilder, idempotent: bool) -> Result<Response, Error> {
+        let mut attempt: u32 = 1;
+
+        loop {
+            // Requests with a streaming body can't be cloned, so can't be retried
+            let next: Option<RequestBuilder> = if attempt < self.retry_policy.max_attempts {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let result: Result<Response, reqwest::Error> = request.send().await;
+
+            let Some(next) = next else {
+                return Ok(result?);
+            };
+
+            let delay: Duration = match result {
+                Ok(response)
+                    if idempotent && self.retry_policy.is_retryable_status(response.status()) =>
+                {
+                    match self.retry_policy.retry_after(&response) {
+                        // The server asks to wait longer than the policy allows: give up
+                        Some(delay) if delay > self.retry_policy.max_backoff => {
+                            return Ok(response);
+                        }
+                        Some(delay) => delay,
+                        None => self.retry_policy.delay(attempt),
+                    }
+                }
+                Ok(response) => return Ok(response),
+                Err(e) if self.retry_policy.is_retryable_error(&e, idempotent) => {
+                    self.retry_policy.delay(attempt)
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            tokio::time::sleep(delay).await;
+
+            request = next;
+            attempt += 1;
+        }
+    }
+
+    pub(crate) async fn get_response<T>(&self, url: Url) -> Result<T, Error>
     where
         T: DeserializeOwned,
     {
-        let response: Response = self.client.get(url).send().await?;
+        let response: Response = self.send(self.client.get(url), true).await?;
         let response: MempoolResponse<T> = response.json().await?;
         response.into_result()
     }
 
+    /// Send a JSON request.
+    ///
+    /// Errors are returned as [`Error::Mempool`].
+    pub(crate) async fn post_json<B, T>(&self, url: Url, body: &B) -> Result<T, Error>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        match self.post_response(url, body, true).await {
+            // Not a transaction endpoint
+            Err(Error::Rejected(rejection)) => Err(Error::Mempool(rejection.message)),
+            res => res,
+        }
+    }
+
+    /// Send a JSON request to an endpoint accepting transactions.
+    ///
+    /// `400` errors are returned as [`Error::Rejected`].
+    pub(crate) async fn post_response<B, T>(
+        &self,
+        url: Url,
+        body: &B,
+        idempotent: bool,
+    ) -> Result<T, Error>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let response: Response = self
+            .send(self.client.post(url).json(body), idempotent)
+            .await?;
+        let status: StatusCode = response.status();
+
+        // Errors are returned as plain text
+        if !status.is_success() {
+            let text: String = response.text().await?;
+
+            if status == StatusCode::BAD_REQUEST {
+                return Err(Error::Rejected(TransactionRejection::parse(&text)));
+            }
+
+            return Err(Error::Mempool(text));
+        }
+
+        let response: MempoolResponse<T> = response.json().await?;
+        response.into_result()
+    }
+
+    /// Walk the `last_seen_txid` cursor of a `.../txs/chain` endpoint.
+    fn chain_transactions_stream(
+        &self,
+        base_path: String,
+    ) -> impl Stream<Item = Result<TransactionInfo, Error>> + '_ {
+        stream::try_unfold(ChainTransactionsCursor::Start, move |cursor| {
+            let base_path: String = base_path.clone();
+            async move {
+                let path: String = match cursor {
+                    ChainTransactionsCursor::Start => format!("{base_path}/txs/chain"),
+                    ChainTransactionsCursor::LastSeen(txid) => {
+                        format!("{base_path}/txs/chain/{txid}")
+                    }
+                    ChainTransactionsCursor::Done => return Ok::<_, Error>(None),
+                };
+
+                let url: Url = self.endpoint(&path)?;
+                let page: Vec<TransactionInfo> = self.get_response(url).await?;
+
+                // An empty page means that there are no more transactions
+                let cursor: ChainTransactionsCursor = match page.last() {
+                    Some(tx) => ChainTransactionsCursor::LastSeen(tx.txid),
+                    None => ChainTransactionsCursor::Done,
+                };
+
+                Ok(Some((stream::iter(page.into_iter().map(Ok)), cursor)))
+            }
+        })
+        .try_flatten()
+    }
+
+    pub(crate) async fn get_text(&self, url: Url) -> Result<String, Error> {
+        let response: Response = self.send(self.client.get(url), true).await?;
+        let status: StatusCode = response.status();
+        let text: String = response.text().await?;
+
+        if !status.is_success() {
+            return Err(Error::Mempool(text));
+        }
+
+        Ok(text)
+    }
+
+    pub(crate) async fn get_bytes(&self, url: Url) -> Result<Vec<u8>, Error> {
+        let response: Response = self.send(self.client.get(url), true).await?;
+        let status: StatusCode = response.status();
+        let bytes: Vec<u8> = response.bytes().await?.to_vec();
+
+        if !status.is_success() {
+            return Err(Error::Mempool(String::from_utf8_lossy(&bytes).into_owned()));
+        }
+
+        Ok(bytes)
+    }
+
     /// Get details about difficulty adjustment.
     pub async fn get_difficulty_adjustment(&self) -> Result<DifficultyAdjustment, Error> {
-        let url: Url = self.url.join("/api/v1/difficulty-adjustment")?;
+        let url: Url = self.endpoint("/api/v1/difficulty-adjustment")?;
         self.get_response(url).await
     }
 
     /// Get bitcoin latest price denominated in main currencies.
     pub async fn get_prices(&self) -> Result<Prices, Error> {
-        let url: Url = self.url.join("/api/v1/prices")?;
+        let url: Url = self.endpoint("/api/v1/prices")?;
+        self.get_response(url).await
+    }
+
+    /// Get bitcoin historical prices.
+    ///
+    /// If `timestamp` is specified, the price closest to it is returned, otherwise the full history.
+    /// If `currency` is specified, only the prices in that currency (and USD) are returned.
+    pub async fn get_historical_prices(
+        &self,
+        currency: Option<Currency>,
+        timestamp: Option<u64>,
+    ) -> Result<HistoricalPrices, Error> {
+        let mut url: Url = self.endpoint("/api/v1/historical-price")?;
+
+        {
+            let mut query = url.query_pairs_mut();
+
+            if let Some(currency) = currency {
+                query.append_pair("currency", currency.as_str());
+            }
+
+            if let Some(timestamp) = timestamp {
+                query.append_pair("timestamp", &timestamp.to_string());
+            }
+        }
+
         self.get_response(url).await
     }
 
     /// Get details about an address.
     pub async fn get_address(&self, address: &Address) -> Result<AddressStats, Error> {
+        self.check_address_network(address)?;
         let url: Url = self
-            .url
-            .join("/api/address/")?
+            .endpoint("/api/address/")?
             .join(address.to_string().as_str())?;
         self.get_response(url).await
     }
 
+    /// Get details about a transaction.
+    pub async fn get_transaction(&self, txid: Txid) -> Result<TransactionInfo, Error> {
+        let url: Url = self.endpoint("/api/tx/")?.join(txid.to_string().as_str())?;
+        self.get_response(url).await
+    }
+
+    /// Get the confirmation status of a transaction.
+    pub async fn get_transaction_status(&self, txid: Txid) -> Result<TransactionStatus, Error> {
+        let url: Url = self.endpoint(&format!("/api/tx/{txid}/status"))?;
+        self.get_response(url).await
+    }
+
+    /// Get a transaction, decoded from its hex serialization.
+    pub async fn get_transaction_hex(&self, txid: Txid) -> Result<Transaction, Error> {
+        let url: Url = self.endpoint(&format!("/api/tx/{txid}/hex"))?;
+        let hex: String = self.get_text(url).await?;
+        Ok(consensus::encode::deserialize_hex(hex.trim())?)
+    }
+
+    /// Get a transaction, decoded from its raw binary serialization.
+    pub async fn get_raw_transaction(&self, txid: Txid) -> Result<Transaction, Error> {
+        let url: Url = self.endpoint(&format!("/api/tx/{txid}/raw"))?;
+        let bytes: Vec<u8> = self.get_bytes(url).await?;
+        Ok(consensus::deserialize(&bytes)?)
+    }
+
+    /// Get a merkle inclusion proof for a transaction.
+    pub async fn get_transaction_merkle_proof(&self, txid: Txid) -> Result<MerkleProof, Error> {
+        let url: Url = self.endpoint(&format!("/api/tx/{txid}/merkle-proof"))?;
+        self.get_response(url).await
+    }
+
+    /// Get the spending status of a transaction output.
+    ///
+    /// Returns `None` if the output is unspent, and an error if the output is reported
+    /// as spent without its spending details.
+    pub async fn get_outspend(&self, txid: Txid, vout: u32) -> Result<Option<Outspend>, Error> {
+        let url: Url = self.endpoint(&format!("/api/tx/{txid}/outspend/{vout}"))?;
+        let outspend: OutspendResponse = self.get_response(url).await?;
+        outspend.into_outspend()
+    }
+
+    /// Get the spending status of all the outputs of a transaction.
+    ///
+    /// The outspends are returned in output order. Unspent outputs are `None`.
+    pub async fn get_outspends(&self, txid: Txid) -> Result<Vec<Option<Outspend>>, Error> {
+        let url: Url = self.endpoint(&format!("/api/tx/{txid}/outspends"))?;
+        let outspends: Vec<OutspendResponse> = self.get_response(url).await?;
+        outspends
+            .into_iter()
+            .map(OutspendResponse::into_outspend)
+            .collect()
+    }
+
+    /// Get the spending status of all the outputs of many transactions at once.
+    ///
+    /// The txids are requested in batches of 50, the maximum accepted by the server.
+    /// The outspends are returned in the same order as `txids`.
+    pub async fn get_outspends_batch(
+        &self,
+        txids: &[Txid],
+    ) -> Result<Vec<Vec<Option<Outspend>>>, Error> {
+        let mut batch: Vec<Vec<Option<Outspend>>> = Vec::with_capacity(txids.len());
+
+        for chunk in txids.chunks(OUTSPENDS_BATCH_SIZE) {
+            let chunk: Vec<String> = chunk.iter().map(|txid| txid.to_string()).collect();
+
+            let mut url: Url = self.endpoint("/api/v1/outspends")?;
+            url.query_pairs_mut().append_pair("txids", &chunk.join(","));
+
+            let outspends: Vec<Vec<OutspendResponse>> = self.get_response(url).await?;
+            for outspends in outspends {
+                batch.push(
+                    outspends
+                        .into_iter()
+                        .map(OutspendResponse::into_outspend)
+                        .collect::<Result<_, _>>()?,
+                );
+            }
+        }
+
+        Ok(batch)
+    }
+
+    /// Get the CPFP (Child Pays For Parent) information of an unconfirmed transaction.
+    pub async fn get_cpfp_info(&self, txid: Txid) -> Result<CpfpInfo, Error> {
+        let url: Url = self
+            .endpoint("/api/v1/cpfp/")?
+            .join(txid.to_string().as_str())?;
+        self.get_response(url).await
+    }
+
+    /// Get the RBF (Replace By Fee) history of a transaction.
+    pub async fn get_rbf_history(&self, txid: Txid) -> Result<RbfHistory, Error> {
+        let url: Url = self.endpoint(&format!("/api/v1/tx/{txid}/rbf"))?;
+        self.get_response(url).await
+    }
+
+    /// Get the most recent RBF (Replace By Fee) replacement trees.
+    pub async fn get_rbf_replacements(&self) -> Result<Vec<RbfTree>, Error> {
+        let url: Url = self.endpoint("/api/v1/replacements")?;
+        self.get_response(url).await
+    }
+
+    /// Get the most recent full RBF replacement trees (replacements of transactions not signaling RBF).
+    pub async fn get_full_rbf_replacements(&self) -> Result<Vec<RbfTree>, Error> {
+        let url: Url = self.endpoint("/api/v1/fullrbf/replacements")?;
+        self.get_response(url).await
+    }
+
+    /// Broadcast a transaction.
+    ///
+    /// Returns the [`Txid`] of the broadcasted transaction.
+    /// If the node refuses the transaction, [`Error::Rejected`] is returned.
+    #[inline]
+    pub async fn broadcast(&self, tx: &Transaction) -> Result<Txid, Error> {
+        self.broadcast_hex(&consensus::encode::serialize_hex(tx))
+            .await
+    }
+
+    /// Broadcast a hex-encoded raw transaction.
+    ///
+    /// Returns the [`Txid`] of the broadcasted transaction.
+    /// If the node refuses the transaction, [`Error::Rejected`] is returned.
+    pub async fn broadcast_hex(&self, tx_hex: &str) -> Result<Txid, Error> {
+        let url: Url = self.endpoint("/api/tx")?;
+        let response: Response = self
+            .send(
+                self.client
+                    .post(url)
+                    .header(CONTENT_TYPE, "text/plain")
+                    .body(tx_hex.to_string()),
+                false,
+            )
+            .await?;
+        let status: StatusCode = response.status();
+        let text: String = response.text().await?;
+
+        if status == StatusCode::BAD_REQUEST {
+            return Err(Error::Rejected(TransactionRejection::parse(&text)));
+        }
+
+        if !status.is_success() {
+            return Err(Error::Mempool(text));
+        }
+
+        Ok(Txid::from_str(text.trim())?)
+    }
+
+    /// Submit a package of transactions (e.g., a parent and a child paying for it).
+    ///
+    /// The transactions must be topologically sorted, with the child last.
+    pub async fn submit_package(&self, txs: &[Transaction]) -> Result<PackageSubmission, Error> {
+        let url: Url = self.endpoint("/api/v1/txs/package")?;
+        let txs: Vec<String> = txs.iter().map(consensus::encode::serialize_hex).collect();
+        self.post_response(url, &txs, false).await
+    }
+
+    /// Test whether the transactions would be accepted to the mempool, without broadcasting them.
+    pub async fn test_mempool_accept(
+        &self,
+        txs: &[Transaction],
+    ) -> Result<Vec<MempoolAcceptResult>, Error> {
+        let url: Url = self.endpoint("/api/txs/test")?;
+        let txs: Vec<String> = txs.iter().map(consensus::encode::serialize_hex).collect();
+        self.post_response(url, &txs, true).await
+    }
+
+    /// Get the transaction history of an address.
+    ///
+    /// Returns up to 50 mempool transactions plus the first 25 confirmed transactions.
+    pub async fn get_address_transactions(
+        &self,
+        address: &Address,
+    ) -> Result<Vec<TransactionInfo>, Error> {
+        self.check_address_network(address)?;
+        let url: Url = self.endpoint(&format!("/api/address/{address}/txs"))?;
+        self.get_response(url).await
+    }
+
+    /// Get the confirmed transaction history of an address, newest first.
+    ///
+    /// Returns 25 transactions per page.
+    /// To get the next page, pass the last transaction ID of the previous page as `last_seen_txid`.
+    pub async fn get_address_chain_transactions(
+        &self,
+        address: &Address,
+        last_seen_txid: Option<Txid>,
+    ) -> Result<Vec<TransactionInfo>, Error> {
+        self.check_address_network(address)?;
+        let path: String = match last_seen_txid {
+            Some(txid) => format!("/api/address/{address}/txs/chain/{txid}"),
+            None => format!("/api/address/{address}/txs/chain"),
+        };
+        let url: Url = self.endpoint(&path)?;
+        self.get_response(url).await
+    }
+
+    /// Get the unconfirmed transactions of an address.
+    ///
+    /// Returns up to 50 transactions (no paging).
+    pub async fn get_address_mempool_transactions(
+        &self,
+        address: &Address,
+    ) -> Result<Vec<TransactionInfo>, Error> {
+        self.check_address_network(address)?;
+        let url: Url = self.endpoint(&format!("/api/address/{address}/txs/mempool"))?;
+        self.get_response(url).await
+    }
+
+    /// Stream the whole confirmed transaction history of an address, newest first.
+    ///
+    /// Pages are fetched lazily, following the `last_seen_txid` cursor.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    /// use mempoolspace::prelude::*;
+    ///
+    /// # async fn run(client: MempoolClient, address: Address) -> Result<(), mempoolspace::error::Error> {
+    /// let stream = client.stream_address_chain_transactions(&address);
+    /// let txs: Vec<TransactionInfo> = stream.try_collect().await?;
+    /// # let _txs = txs;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_address_chain_transactions(
+        &self,
+        address: &Address,
+    ) -> impl Stream<Item = Result<TransactionInfo, Error>> + '_ {
+        match self.check_address_network(address) {
+            Ok(()) => self
+                .chain_transactions_stream(format!("/api/address/{address}"))
+                .left_stream(),
+            Err(e) => stream::once(future::ready(Err(e))).right_stream(),
+        }
+    }
+
+    /// Get the unspent transaction outputs of an address.
+    pub async fn get_address_utxos(&self, address: &Address) -> Result<Vec<Utxo>, Error> {
+        self.check_address_network(address)?;
+        let url: Url = self.endpoint(&format!("/api/address/{address}/utxo"))?;
+        self.get_response(url).await
+    }
+
+    /// Get details about a script.
+    ///
+    /// Useful for scripts that don't have an address representation (e.g., bare multisig).
+    pub async fn get_scripthash(&self, script: &Script) -> Result<ScriptHashStats, Error> {
+        let scripthash: String = electrum_script_hash(script);
+        let url: Url = self.endpoint(&format!("/api/scripthash/{scripthash}"))?;
+        self.get_response(url).await
+    }
+
+    /// Get the transaction history of a script.
+    ///
+    /// Returns up to 50 mempool transactions plus the first 25 confirmed transactions.
+    pub async fn get_scripthash_transactions(
+        &self,
+        script: &Script,
+    ) -> Result<Vec<TransactionInfo>, Error> {
+        let scripthash: String = electrum_script_hash(script);
+        let url: Url = self.endpoint(&format!("/api/scripthash/{scripthash}/txs"))?;
+        self.get_response(url).await
+    }
+
+    /// Get the confirmed transaction history of a script, newest first.
+    ///
+    /// Returns 25 transactions per page.
+    /// To get the next page, pass the last transaction ID of the previous page as `last_seen_txid`.
+    pub async fn get_scripthash_chain_transactions(
+        &self,
+        script: &Script,
+        last_seen_txid: Option<Txid>,
+    ) -> Result<Vec<TransactionInfo>, Error> {
+        let scripthash: String = electrum_script_hash(script);
+        let path: String = match last_seen_txid {
+            Some(txid) => format!("/api/scripthash/{scripthash}/txs/chain/{txid}"),
+            None => format!("/api/scripthash/{scripthash}/txs/chain"),
+        };
+        let url: Url = self.endpoint(&path)?;
+        self.get_response(url).await
+    }
+
+    /// Get the unconfirmed transactions of a script.
+    ///
+    /// Returns up to 50 transactions (no paging).
+    pub async fn get_scripthash_mempool_transactions(
+        &self,
+        script: &Script,
+    ) -> Result<Vec<TransactionInfo>, Error> {
+        let scripthash: String = electrum_script_hash(script);
+        let url: Url = self.endpoint(&format!("/api/scripthash/{scripthash}/txs/mempool"))?;
+        self.get_response(url).await
+    }
+
+    /// Stream the whole confirmed transaction history of a script, newest first.
+    ///
+    /// Pages are fetched lazily, following the `last_seen_txid` cursor.
+    pub fn stream_scripthash_chain_transactions(
+        &self,
+        script: &Script,
+    ) -> impl Stream<Item = Result<TransactionInfo, Error>> + '_ {
+        let scripthash: String = electrum_script_hash(script);
+        self.chain_transactions_stream(format!("/api/scripthash/{scripthash}"))
+    }
+
+    /// Get the unspent transaction outputs of a script.
+    pub async fn get_scripthash_utxos(&self, script: &Script) -> Result<Vec<Utxo>, Error> {
+        let scripthash: String = electrum_script_hash(script);
+        let url: Url = self.endpoint(&format!("/api/scripthash/{scripthash}/utxo"))?;
+        self.get_response(url).await
+    }
+
     /// Get the height of the last block.
     pub async fn get_block_tip_height(&self) -> Result<u32, Error> {
-        let url: Url = self.url.join("/api/blocks/tip/height")?;
+        let url: Url = self.endpoint("/api/blocks/tip/height")?;
+        self.get_response(url).await
+    }
+
+    /// Get the hash of the last block.
+    pub async fn get_block_tip_hash(&self) -> Result<BlockHash, Error> {
+        let url: Url = self.endpoint("/api/blocks/tip/hash")?;
+        let hash: String = self.get_text(url).await?;
+        Ok(BlockHash::from_str(hash.trim())?)
+    }
+
+    /// Get the hash of the block at `height` in the best chain.
+    pub async fn get_block_hash(&self, height: u32) -> Result<BlockHash, Error> {
+        let url: Url = self.endpoint(&format!("/api/block-height/{height}"))?;
+        let hash: String = self.get_text(url).await?;
+        Ok(BlockHash::from_str(hash.trim())?)
+    }
+
+    /// Get the confirmation status of a block.
+    pub async fn get_block_status(&self, hash: BlockHash) -> Result<BlockStatus, Error> {
+        let url: Url = self.endpoint(&format!("/api/block/{hash}/status"))?;
         self.get_response(url).await
     }
 
     /// Get the block information
     pub async fn get_block(&self, hash: BlockHash) -> Result<BlockInfo, Error> {
         let url: Url = self
-            .url
-            .join("/api/block/")?
+            .endpoint("/api/block/")?
             .join(hash.to_string().as_str())?;
         self.get_response(url).await
     }
@@ -135,50 +773,254 @@ impl MempoolClient {
     /// Get the block information (v1)
     pub async fn get_block_v1(&self, hash: BlockHash) -> Result<BlockInfoV1, Error> {
         let url: Url = self
-            .url
-            .join("/api/v1/block/")?
+            .endpoint("/api/v1/block/")?
             .join(hash.to_string().as_str())?;
         self.get_response(url).await
     }
 
+    /// Get the IDs of all the transactions in a block.
+    pub async fn get_block_txids(&self, hash: BlockHash) -> Result<Vec<Txid>, Error> {
+        let url: Url = self.endpoint(&format!("/api/block/{hash}/txids"))?;
+        self.get_response(url).await
+    }
+
+    /// Get the ID of the transaction at `index` in a block.
+    pub async fn get_block_txid(&self, hash: BlockHash, index: u32) -> Result<Txid, Error> {
+        let url: Url = self.endpoint(&format!("/api/block/{hash}/txid/{index}"))?;
+        let txid: String = self.get_text(url).await?;
+        Ok(Txid::from_str(txid.trim())?)
+    }
+
+    /// Get the transactions in a block, starting at `start_index`.
+    ///
+    /// Returns 25 transactions per page. The `start_index` must be a multiple of 25.
+    pub async fn get_block_transactions(
+        &self,
+        hash: BlockHash,
+        start_index: u32,
+    ) -> Result<Vec<TransactionInfo>, Error> {
+        let url: Url = self.endpoint(&format!("/api/block/{hash}/txs/{start_index}"))?;
+        self.get_response(url).await
+    }
+
+    /// Stream all the transactions in a block.
+    ///
+    /// Pages are fetched lazily.
+    pub fn stream_block_transactions(
+        &self,
+        hash: BlockHash,
+    ) -> impl Stream<Item = Result<TransactionInfo, Error>> + '_ {
+        stream::try_unfold(
+            (0, None),
+            move |(start_index, tx_count): (u32, Option<u32>)| async move {
+                // Get the number of transactions in the block, if not already known
+                let tx_count: u32 = match tx_count {
+                    Some(tx_count) => tx_count,
+                    None => self.get_block(hash).await?.tx_count,
+                };
+
+                if start_index >= tx_count {
+                    return Ok::<_, Error>(None);
+                }
+
+                let page: Vec<TransactionInfo> =
+                    self.get_block_transactions(hash, start_index).await?;
+                let next: (u32, Option<u32>) = (start_index + BLOCK_TXS_PAGE_SIZE, Some(tx_count));
+
+                Ok(Some((stream::iter(page.into_iter().map(Ok)), next)))
+            },
+        )
+        .try_flatten()
+    }
+
+    /// Get a block, decoded from its raw binary serialization.
+    pub async fn get_raw_block(&self, hash: BlockHash) -> Result<Block, Error> {
+        let url: Url = self.endpoint(&format!("/api/block/{hash}/raw"))?;
+        let bytes: Vec<u8> = self.get_bytes(url).await?;
+        Ok(consensus::deserialize(&bytes)?)
+    }
+
+    /// Get a block header, decoded from its hex serialization.
+    pub async fn get_block_header(&self, hash: BlockHash) -> Result<Header, Error> {
+        let url: Url = self.endpoint(&format!("/api/block/{hash}/header"))?;
+        let hex: String = self.get_text(url).await?;
+        Ok(consensus::encode::deserialize_hex(hex.trim())?)
+    }
+
+    /// Get the audit summary of a block, comparing it with the expected block template.
+    pub async fn get_block_audit_summary(
+        &self,
+        hash: BlockHash,
+    ) -> Result<BlockAuditSummary, Error> {
+        let url: Url = self.endpoint(&format!("/api/v1/block/{hash}/audit-summary"))?;
+        self.get_response(url).await
+    }
+
+    /// Get the stripped transactions of a block.
+    pub async fn get_block_summary(
+        &self,
+        hash: BlockHash,
+    ) -> Result<Vec<StrippedTransaction>, Error> {
+        let url: Url = self.endpoint(&format!("/api/v1/block/{hash}/summary"))?;
+        self.get_response(url).await
+    }
+
     /// Get the details on the past 10 blocks.
     ///
     /// If `start_height` is specified, the 10 blocks before (and including) `start_height` are returned.
     pub async fn get_blocks(&self, start_height: Option<u32>) -> Result<Vec<BlockInfo>, Error> {
-        let mut url: Url = self.url.join("/api/blocks")?;
-
-        // Add start height, if any.
-        if let Some(start_height) = start_height {
-            url = url.join(start_height.to_string().as_str())?;
-        }
-
+        let path: String = match start_height {
+            Some(start_height) => format!("/api/blocks/{start_height}"),
+            None => String::from("/api/blocks"),
+        };
+        let url: Url = self.endpoint(&path)?;
         self.get_response(url).await
     }
 
     /// Get network-wide hashrate and difficulty figures over the last 3 days.
     pub async fn get_hashrate(&self, period: HashratePeriod) -> Result<HashrateStats, Error> {
         let url: Url = self
-            .url
-            .join("/api/v1/mining/hashrate/")?
+            .endpoint("/api/v1/mining/hashrate/")?
+            .join(period.as_str())?;
+        self.get_response(url).await
+    }
+
+    /// Get the mining pools ranking over a period.
+    pub async fn get_mining_pools(
+        &self,
+        period: HashratePeriod,
+    ) -> Result<MiningPoolsRanking, Error> {
+        let url: Url = self
+            .endpoint("/api/v1/mining/pools/")?
+            .join(period.as_str())?;
+        self.get_response(url).await
+    }
+
+    /// Get the details of a mining pool.
+    pub async fn get_mining_pool(&self, slug: &str) -> Result<MiningPoolDetails, Error> {
+        let url: Url = self.endpoint_with_segments("/api/v1/mining/pool", &[slug])?;
+        self.get_response(url).await
+    }
+
+    /// Get the hashrate history of a mining pool.
+    pub async fn get_mining_pool_hashrate(
+        &self,
+        slug: &str,
+    ) -> Result<BTreeSet<PoolHashrateEntry>, Error> {
+        let url: Url = self.endpoint_with_segments("/api/v1/mining/pool", &[slug, "hashrate"])?;
+        self.get_response(url).await
+    }
+
+    /// Get the last 10 blocks mined by a mining pool.
+    ///
+    /// If `start_height` is specified, the 10 blocks before (and including) `start_height` are returned.
+    pub async fn get_mining_pool_blocks(
+        &self,
+        slug: &str,
+        start_height: Option<u32>,
+    ) -> Result<Vec<BlockInfoV1>, Error> {
+        let url: Url = match start_height {
+            Some(start_height) => self.endpoint_with_segments(
+                "/api/v1/mining/pool",
+                &[slug, "blocks", &start_height.to_string()],
+            )?,
+            None => self.endpoint_with_segments("/api/v1/mining/pool", &[slug, "blocks"])?,
+        };
+        self.get_response(url).await
+    }
+
+    /// Get the average total fees per block over a period.
+    pub async fn get_block_fees(
+        &self,
+        period: HashratePeriod,
+    ) -> Result<BTreeSet<BlockFeesEntry>, Error> {
+        let url: Url = self
+            .endpoint("/api/v1/mining/blocks/fees/")?
+            .join(period.as_str())?;
+        self.get_response(url).await
+    }
+
+    /// Get the average block rewards over a period.
+    pub async fn get_block_rewards(
+        &self,
+        period: HashratePeriod,
+    ) -> Result<BTreeSet<BlockRewardsEntry>, Error> {
+        let url: Url = self
+            .endpoint("/api/v1/mining/blocks/rewards/")?
+            .join(period.as_str())?;
+        self.get_response(url).await
+    }
+
+    /// Get the average block fee rate percentiles over a period.
+    pub async fn get_block_fee_rates(
+        &self,
+        period: HashratePeriod,
+    ) -> Result<BTreeSet<BlockFeeRatesEntry>, Error> {
+        let url: Url = self
+            .endpoint("/api/v1/mining/blocks/fee-rates/")?
+            .join(period.as_str())?;
+        self.get_response(url).await
+    }
+
+    /// Get the average block sizes and weights over a period.
+    pub async fn get_block_sizes_and_weights(
+        &self,
+        period: HashratePeriod,
+    ) -> Result<BlockSizesAndWeights, Error> {
+        let url: Url = self
+            .endpoint("/api/v1/mining/blocks/sizes-weights/")?
+            .join(period.as_str())?;
+        self.get_response(url).await
+    }
+
+    /// Get the block mined at (or right before) a UNIX timestamp.
+    pub async fn get_block_by_timestamp(&self, timestamp: u64) -> Result<BlockTimestamp, Error> {
+        let url: Url = self
+            .endpoint("/api/v1/mining/blocks/timestamp/")?
+            .join(timestamp.to_string().as_str())?;
+        self.get_response(url).await
+    }
+
+    /// Get all the difficulty adjustments over a period.
+    ///
+    /// The [`DifficultyEntry::adjustment`] is the ratio between the new and the previous difficulty.
+    pub async fn get_difficulty_adjustments(
+        &self,
+        period: HashratePeriod,
+    ) -> Result<BTreeSet<DifficultyEntry>, Error> {
+        let url: Url = self
+            .endpoint("/api/v1/mining/difficulty-adjustments/")?
+            .join(period.as_str())?;
+        let adjustments: Vec<DifficultyAdjustmentResponse> = self.get_response(url).await?;
+        Ok(adjustments.into_iter().map(DifficultyEntry::from).collect())
+    }
+
+    /// Get the audit scores of the blocks mined over a period.
+    pub async fn get_block_audits(
+        &self,
+        period: HashratePeriod,
+    ) -> Result<BTreeSet<BlockAuditEntry>, Error> {
+        let url: Url = self
+            .endpoint("/api/v1/mining/blocks/audit/")?
             .join(period.as_str())?;
         self.get_response(url).await
     }
 
     /// Get currently suggested fees for new transactions.
     pub async fn get_recommended_fees(&self) -> Result<FeeRecommendations, Error> {
-        let url: Url = self.url.join("/api/v1/fees/recommended")?;
+        let url: Url = self.endpoint("/api/v1/fees/recommended")?;
         self.get_response(url).await
     }
 
     /// Get current mempool backlog statistics.
     pub async fn get_mempool(&self) -> Result<MempoolStats, Error> {
-        let url: Url = self.url.join("/api/mempool")?;
+        let url: Url = self.endpoint("/api/mempool")?;
         self.get_response(url).await
     }
 
     /// Get current mempool as projected blocks.
     pub async fn get_mempool_blocks_fees(&self) -> Result<Vec<MempoolBlockFees>, Error> {
-        let url: Url = self.url.join("/api/v1/fees/mempool-blocks")?;
+        let url: Url = self.endpoint("/api/v1/fees/mempool-blocks")?;
         self.get_response(url).await
     }
 
@@ -191,6 +1033,641 @@ impl MempoolClient {
         &self,
         req: MempoolSubscriptionRequest,
     ) -> Result<MempoolSubscription, Error> {
-        websocket::subscribe(&self.url, req).await
+        let url: Url = self.endpoint("/api/v1/ws")?;
+        websocket::subscribe(&url, req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
+
+    use bitcoin::ScriptBuf;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Spawn a local HTTP server replying with `responses` in order (repeating the last one).
+    ///
+    /// Returns the server URL and the heads (request line and headers) of the requests received.
+    async fn mock_server<S>(responses: Vec<S>) -> (Url, Arc<Mutex<Vec<String>>>)
+    where
+        S: AsRef<str> + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = requests.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+
+                // Read the request headers (the body, if any, is ignored)
+                let mut buf: Vec<u8> = Vec::new();
+                let mut chunk = [0u8; 1024];
+                while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n: usize = socket.read(&mut chunk).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    buf.extend_from_slice(&chunk[..n]);
+                }
+
+                let head: String = String::from_utf8_lossy(&buf).into_owned();
+                let index: usize = {
+                    let mut received = received.lock().unwrap();
+                    received.push(head);
+                    received.len() - 1
+                };
+
+                let response: &str = responses[index.min(responses.len() - 1)].as_ref();
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.unwrap();
+            }
+        });
+
+        (Url::parse(&format!("http://{addr}")).unwrap(), requests)
+    }
+
+    /// Paths of the requests received by a [`mock_server`]
+    fn request_paths(requests: &Mutex<Vec<String>>) -> Vec<String> {
+        requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|head| head.split(' ').nth(1).unwrap_or_default().to_string())
+            .collect()
+    }
+
+    /// Build a `200 OK` response with a JSON body
+    fn json_response(body: &str) -> String {
+        format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    const OK: &str = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 6\r\nconnection: close\r\n\r\n800000";
+    const NOT_FOUND: &str =
+        "HTTP/1.1 404 Not Found\r\ncontent-length: 9\r\nconnection: close\r\n\r\nNot found";
+    const UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+    const TOO_MANY_REQUESTS: &str = "HTTP/1.1 429 Too Many Requests\r\nretry-after: 1\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+    const TOO_MANY_REQUESTS_LONG: &str = "HTTP/1.1 429 Too Many Requests\r\nretry-after: 86400\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+    const TOO_MANY_REQUESTS_DATE: &str = "HTTP/1.1 429 Too Many Requests\r\nretry-after: Fri, 31 Dec 9999 23:59:59 GMT\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+    fn fast_retry_policy() -> RetryPolicy {
+        RetryPolicy::default()
+            .backoff(Duration::from_millis(1), Duration::from_millis(10), 2.0)
+            .jitter(0.0)
+    }
+
+    #[test]
+    fn test_electrum_script_hash() {
+        // P2PKH script of the genesis block address (1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa)
+        let script =
+            ScriptBuf::from_hex("76a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac").unwrap();
+
+        assert_eq!(
+            electrum_script_hash(&script),
+            "8b01df4e368ea28f8dc0423bcf7a4923e3a12d307c875e47a0cfbf90b5c39161"
+        );
+    }
+
+    #[test]
+    fn test_endpoint_with_segments() {
+        let client = MempoolClient::new(Url::parse("https://mempool.space").unwrap());
+
+        assert_eq!(
+            client
+                .endpoint_with_segments("/api/v1/mining/pool", &["foundryusa", "blocks"])
+                .unwrap()
+                .as_str(),
+            "https://mempool.space/api/v1/mining/pool/foundryusa/blocks"
+        );
+        assert_eq!(
+            client
+                .endpoint_with_segments("/api/v1/mining/pool", &["../../tx/abc?x=1#y"])
+                .unwrap()
+                .as_str(),
+            "https://mempool.space/api/v1/mining/pool/..%2F..%2Ftx%2Fabc%3Fx=1%23y"
+        );
+        assert_eq!(
+            client
+                .endpoint_with_segments("/api/v1/mining/pool", &[".."])
+                .unwrap()
+                .as_str(),
+            "https://mempool.space/api/v1/mining/pool/%252E%252E"
+        );
+    }
+
+    #[test]
+    fn test_network_endpoint() {
+        let url = Url::parse("https://mempool.space").unwrap();
+
+        let client = MempoolClient::new(url.clone());
+        assert_eq!(client.network(), Network::Bitcoin);
+        assert_eq!(
+            client.endpoint("/api/blocks/tip/height").unwrap().as_str(),
+            "https://mempool.space/api/blocks/tip/height"
+        );
+
+        for (network, expected) in [
+            (
+                Network::Testnet,
+                "https://mempool.space/testnet/api/v1/prices",
+            ),
+            (
+                Network::Testnet4,
+                "https://mempool.space/testnet4/api/v1/prices",
+            ),
+            (
+                Network::Signet,
+                "https://mempool.space/signet/api/v1/prices",
+            ),
+            (Network::Regtest, "https://mempool.space/api/v1/prices"),
+        ] {
+            let client = MempoolClient::builder(url.clone())
+                .network(network)
+                .build()
+                .unwrap();
+            assert_eq!(
+                client.endpoint("/api/v1/prices").unwrap().as_str(),
+                expected
+            );
+        }
+
+        // Overridden or disabled prefix
+        for (prefix, expected) in [
+            ("", "https://mempool.space/api/v1/prices"),
+            ("/", "https://mempool.space/api/v1/prices"),
+            ("/sig", "https://mempool.space/sig/api/v1/prices"),
+            ("sig/", "https://mempool.space/sig/api/v1/prices"),
+        ] {
+            let client = MempoolClient::builder(url.clone())
+                .network(Network::Signet)
+                .network_prefix(prefix)
+                .build()
+                .unwrap();
+            assert_eq!(client.network(), Network::Signet);
+            assert_eq!(
+                client.endpoint("/api/v1/prices").unwrap().as_str(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_base_url_path_prefix() {
+        for (base, expected) in [
+            (
+                "https://mempool.space",
+                "https://mempool.space/api/blocks/tip/height",
+            ),
+            (
+                "https://mempool.space/",
+                "https://mempool.space/api/blocks/tip/height",
+            ),
+            (
+                "https://infra.example/mempool",
+                "https://infra.example/mempool/api/blocks/tip/height",
+            ),
+            (
+                "https://infra.example/mempool/",
+                "https://infra.example/mempool/api/blocks/tip/height",
+            ),
+            (
+                "http://127.0.0.1:8080/a/b/",
+                "http://127.0.0.1:8080/a/b/api/blocks/tip/height",
+            ),
+        ] {
+            let client = MempoolClient::new(Url::parse(base).unwrap());
+            assert_eq!(
+                client.endpoint("/api/blocks/tip/height").unwrap().as_str(),
+                expected
+            );
+        }
+
+        // Network prefix goes after the base path
+        let client = MempoolClient::builder(Url::parse("https://infra.example/mempool/").unwrap())
+            .network(Network::Signet)
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.endpoint("/api/v1/ws").unwrap().as_str(),
+            "https://infra.example/mempool/signet/api/v1/ws"
+        );
+
+        // Chained relative segments
+        let hash =
+            BlockHash::from_str("000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f")
+                .unwrap();
+        let url: Url = client
+            .endpoint("/api/block/")
+            .unwrap()
+            .join(hash.to_string().as_str())
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://infra.example/mempool/signet/api/block/000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+        );
+    }
+
+    #[test]
+    fn test_address_network_check() {
+        let url = Url::parse("https://mempool.space").unwrap();
+        let mainnet = Address::from_str("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")
+            .unwrap()
+            .assume_checked();
+        let testnet = Address::from_str("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx")
+            .unwrap()
+            .assume_checked();
+
+        let client = MempoolClient::new(url.clone());
+        assert!(client.check_address_network(&mainnet).is_ok());
+        assert!(matches!(
+            client.check_address_network(&testnet),
+            Err(Error::NetworkMismatch {
+                expected: Network::Bitcoin,
+                ..
+            })
+        ));
+
+        // Testnet addresses are valid on signet too
+        let client = MempoolClient::builder(url)
+            .network(Network::Signet)
+            .build()
+            .unwrap();
+        assert!(client.check_address_network(&testnet).is_ok());
+        assert!(client.check_address_network(&mainnet).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_self_hosted_network_without_prefix() {
+        let (url, requests) = mock_server(vec![OK]).await;
+        let client = MempoolClient::builder(url)
+            .network(Network::Signet)
+            .network_prefix("")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.get_block_tip_height().await.unwrap(), 800000);
+        assert_eq!(request_paths(&requests), ["/api/blocks/tip/height"]);
+
+        // Addresses are still validated against the network
+        let mainnet = Address::from_str("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")
+            .unwrap()
+            .assume_checked();
+        assert!(matches!(
+            client.get_address(&mainnet).await,
+            Err(Error::NetworkMismatch {
+                expected: Network::Signet,
+                ..
+            })
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_errors() {
+        let (url, requests) = mock_server(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+        let client = MempoolClient::builder(url)
+            .retry_policy(fast_retry_policy())
+            .build()
+            .unwrap();
+
+        assert_eq!(client.get_block_tip_height().await.unwrap(), 800000);
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_max_attempts() {
+        let (url, requests) = mock_server(vec![UNAVAILABLE]).await;
+        let client = MempoolClient::builder(url)
+            .retry_policy(fast_retry_policy().max_attempts(2))
+            .build()
+            .unwrap();
+
+        assert!(client.get_block_tip_height().await.is_err());
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_by_default() {
+        let (url, requests) = mock_server(vec![UNAVAILABLE, OK]).await;
+        let client = MempoolClient::new(url);
+
+        assert!(client.get_block_tip_height().await.is_err());
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_on_non_retryable_status() {
+        let (url, requests) = mock_server(vec![NOT_FOUND, OK]).await;
+        let client = MempoolClient::builder(url)
+            .retry_policy(fast_retry_policy())
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            client.get_block_tip_hash().await,
+            Err(Error::Mempool(msg)) if msg == "Not found"
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after() {
+        let (url, requests) = mock_server(vec![TOO_MANY_REQUESTS, OK]).await;
+
+        // Retry-After is honoured (up to the max backoff)
+        let client = MempoolClient::builder(url.clone())
+            .retry_policy(fast_retry_policy().backoff(
+                Duration::from_millis(1),
+                Duration::from_secs(2),
+                2.0,
+            ))
+            .build()
+            .unwrap();
+        let start = Instant::now();
+        assert_eq!(client.get_block_tip_height().await.unwrap(), 800000);
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        // Retry-After is ignored
+        let (url, requests) = mock_server(vec![TOO_MANY_REQUESTS, OK]).await;
+        let client = MempoolClient::builder(url)
+            .retry_policy(fast_retry_policy().respect_retry_after(false))
+            .build()
+            .unwrap();
+        let start = Instant::now();
+        assert_eq!(client.get_block_tip_height().await.unwrap(), 800000);
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_exceeds_max_backoff() {
+        for response in [TOO_MANY_REQUESTS_LONG, TOO_MANY_REQUESTS_DATE] {
+            let (url, requests) = mock_server(vec![response, OK]).await;
+            let client = MempoolClient::builder(url)
+                .retry_policy(fast_retry_policy())
+                .build()
+                .unwrap();
+
+            // Give up instead of sleeping for a day
+            let start = Instant::now();
+            assert!(client.get_block_tip_height().await.is_err());
+            assert!(start.elapsed() < Duration::from_secs(1));
+            assert_eq!(requests.lock().unwrap().len(), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_on_broadcast_server_error() {
+        const BAD_GATEWAY: &str = "HTTP/1.1 502 Bad Gateway\r\ncontent-length: 11\r\nconnection: close\r\n\r\nBad Gateway";
+
+        let (url, requests) = mock_server(vec![BAD_GATEWAY, OK]).await;
+        let client = MempoolClient::builder(url)
+            .retry_policy(fast_retry_policy())
+            .build()
+            .unwrap();
+
+        // The transaction may have been accepted: don't retry
+        assert!(matches!(
+            client.broadcast_hex("0200").await,
+            Err(Error::Mempool(msg)) if msg == "Bad Gateway"
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    const TXID_1: &str = "b1fea52486ce0c62bb442b530a3f0132b826c74e473d1f2c220bfa78111c5082";
+    const TXID_2: &str = "f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16";
+    const TXID_3: &str = "0437cd7f8525ceed2324359c2d0ba26006d92d856a9c20fa0241106ee5a597c9";
+
+    /// Build a page of confirmed transactions (only the txids matter to the streams)
+    fn transactions_page(txids: &[&str]) -> String {
+        let txs: Vec<String> = txids
+            .iter()
+            .map(|txid| {
+                format!(
+                    r#"{{"txid":"{txid}","version":1,"locktime":0,"vin":[],"vout":[],"size":134,"weight":536,"fee":0,"status":{{"confirmed":true}}}}"#
+                )
+            })
+            .collect();
+        json_response(&format!("[{}]", txs.join(",")))
+    }
+
+    async fn collect_txids<S>(stream: S) -> Vec<Result<String, Error>>
+    where
+        S: Stream<Item = Result<TransactionInfo, Error>>,
+    {
+        stream
+            .map(|tx| tx.map(|tx| tx.txid.to_string()))
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_stream_address_chain_transactions() {
+        let (url, requests) = mock_server(vec![
+            transactions_page(&[TXID_1, TXID_2]),
+            transactions_page(&[TXID_3]),
+            transactions_page(&[]),
+        ])
+        .await;
+        let client = MempoolClient::new(url);
+        let address = Address::from_str("12cbQLTFMXRnSzktFkuoG3eHoMeFtpTu3S")
+            .unwrap()
+            .assume_checked();
+
+        let txids: Vec<String> = collect_txids(client.stream_address_chain_transactions(&address))
+            .await
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(txids, [TXID_1, TXID_2, TXID_3]);
+        assert_eq!(
+            request_paths(&requests),
+            [
+                "/api/address/12cbQLTFMXRnSzktFkuoG3eHoMeFtpTu3S/txs/chain".to_string(),
+                format!("/api/address/12cbQLTFMXRnSzktFkuoG3eHoMeFtpTu3S/txs/chain/{TXID_2}"),
+                format!("/api/address/12cbQLTFMXRnSzktFkuoG3eHoMeFtpTu3S/txs/chain/{TXID_3}"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_scripthash_chain_transactions_error() {
+        let (url, requests) = mock_server(vec![
+            transactions_page(&[TXID_1, TXID_2]),
+            UNAVAILABLE.to_string(),
+            transactions_page(&[]),
+        ])
+        .await;
+        let client = MempoolClient::new(url);
+        let script = Address::from_str("12cbQLTFMXRnSzktFkuoG3eHoMeFtpTu3S")
+            .unwrap()
+            .assume_checked()
+            .script_pubkey();
+        let scripthash: String = electrum_script_hash(&script);
+
+        let txids = collect_txids(client.stream_scripthash_chain_transactions(&script)).await;
+
+        // The stream ends after the first error
+        assert_eq!(txids.len(), 3);
+        assert_eq!(txids[0].as_deref().unwrap(), TXID_1);
+        assert_eq!(txids[1].as_deref().unwrap(), TXID_2);
+        assert!(txids[2].is_err());
+        assert_eq!(
+            request_paths(&requests),
+            [
+                format!("/api/scripthash/{scripthash}/txs/chain"),
+                format!("/api/scripthash/{scripthash}/txs/chain/{TXID_2}"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_block_transactions() {
+        // Block 170, with the transaction count raised to span two pages
+        let block: String = json_response(
+            r#"{"id":"00000000d1145790a8694403d4063f323d499e655c83426834d4ce2f8dd4a2ee","height":170,"version":1,"timestamp":1231731025,"tx_count":27,"size":490,"weight":1960,"merkle_root":"7dac2c5666815c17a3b36427de37bb9d2e2c5ccec3f8633eb91a4205cb4c10ff","previousblockhash":"000000002a22cfee1f2c846adbd12b3e183d4f97683f85dad08a79780a84bd55","mediantime":1231716245,"nonce":1889418792,"bits":486604799,"difficulty":1}"#,
+        );
+        let (url, requests) = mock_server(vec![
+            block,
+            transactions_page(&[TXID_1, TXID_2]),
+            transactions_page(&[TXID_3]),
+        ])
+        .await;
+        let client = MempoolClient::new(url);
+        let hash =
+            BlockHash::from_str("00000000d1145790a8694403d4063f323d499e655c83426834d4ce2f8dd4a2ee")
+                .unwrap();
+
+        let txids: Vec<String> = collect_txids(client.stream_block_transactions(hash))
+            .await
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(txids, [TXID_1, TXID_2, TXID_3]);
+        assert_eq!(
+            request_paths(&requests),
+            [
+                format!("/api/block/{hash}"),
+                format!("/api/block/{hash}/txs/0"),
+                format!("/api/block/{hash}/txs/25"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_outspends_batch() {
+        // Genesis coinbase (unspendable), and block 9 coinbase spent by the first bitcoin transaction
+        const GENESIS_OUTSPENDS: &str = r#"[{"spent":false}]"#;
+        const BLOCK_9_OUTSPENDS: &str = r#"[{"spent":true,"txid":"f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16","vin":0,"status":{"confirmed":true,"block_height":170,"block_hash":"00000000d1145790a8694403d4063f323d499e655c83426834d4ce2f8dd4a2ee","block_time":1231731025}}]"#;
+
+        let mut first: Vec<&str> = vec![GENESIS_OUTSPENDS, BLOCK_9_OUTSPENDS];
+        first.extend([GENESIS_OUTSPENDS; 48]);
+        let first: String = json_response(&format!("[{}]", first.join(",")));
+        let second: String = json_response(&format!("[{GENESIS_OUTSPENDS}]"));
+        let (url, requests) = mock_server(vec![first, second]).await;
+        let client = MempoolClient::new(url);
+
+        let genesis =
+            Txid::from_str("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b")
+                .unwrap();
+        let block_9 =
+            Txid::from_str("0437cd7f8525ceed2324359c2d0ba26006d92d856a9c20fa0241106ee5a597c9")
+                .unwrap();
+
+        // Nothing to request
+        assert!(client.get_outspends_batch(&[]).await.unwrap().is_empty());
+        assert!(requests.lock().unwrap().is_empty());
+
+        // 51 txids are split into a batch of 50 and a batch of 1
+        let mut txids: Vec<Txid> = vec![genesis, block_9];
+        txids.extend([genesis; 49]);
+        let outspends = client.get_outspends_batch(&txids).await.unwrap();
+
+        assert_eq!(outspends.len(), 51);
+        assert_eq!(outspends[0], [None]);
+        assert_eq!(outspends[1].len(), 1);
+        assert_eq!(outspends[1][0].unwrap().vin, 0);
+        assert_eq!(outspends[1][0].unwrap().status.block_height, Some(170));
+        assert_eq!(outspends[50], [None]);
+
+        let requests: Vec<String> = request_paths(&requests);
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].matches("%2C").count(), 49);
+        assert!(requests[1].ends_with(&format!("?txids={genesis}")));
+    }
+
+    #[tokio::test]
+    async fn test_get_outspend_spent_without_details() {
+        let (url, _) = mock_server(vec![
+            json_response(r#"{"spent":true}"#),
+            json_response(r#"[[{"spent":false}],[{"spent":true}]]"#),
+        ])
+        .await;
+        let client = MempoolClient::new(url);
+
+        let block_9 =
+            Txid::from_str("0437cd7f8525ceed2324359c2d0ba26006d92d856a9c20fa0241106ee5a597c9")
+                .unwrap();
+
+        // A spent output must never be reported as unspent
+        assert!(matches!(
+            client.get_outspend(block_9, 0).await,
+            Err(Error::Mempool(_))
+        ));
+        assert!(matches!(
+            client.get_outspends_batch(&[block_9, block_9]).await,
+            Err(Error::Mempool(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_hex() {
+        // The first transaction, to Hal Finney
+        const TX_HEX: &str = "0100000001c997a5e56e104102fa209c6a852dd90660a20b2d9c352423edce25857fcd3704000000004847304402204e45e16932b8af514961a1d3a1a25fdf3f4f7732e9d624c6c61548ab5fb8cd410220181522ec8eca07de4860a4acdd12909d831cc56cbbac4622082221a8768d1d0901ffffffff0200ca9a3b00000000434104ae1a62fe09c5f51b13905f07f06b99a2f7159b2225f374cd378d71302fa28414e7aab37397f554a7df5f142c21c1b7303b8a0626f1baded5c72a704f7e6cd84cac00286bee0000000043410411db93e1dcdb8a016b49840f8c53bc1eb68a382e97b1482ecad7b148a6909a5cb2e0eaddfb84ccf9744464f82e160bfa9b8b64f9d4c03f999b8643f656b412a3ac00000000";
+        const ACCEPTED: &str = "HTTP/1.1 200 OK\r\ncontent-type: text/plain\r\ncontent-length: 64\r\nconnection: close\r\n\r\nf4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16";
+        const REJECTED: &str = "HTTP/1.1 400 Bad Request\r\ncontent-type: text/plain\r\ncontent-length: 94\r\nconnection: close\r\n\r\nsendrawtransaction RPC error: {\"code\":-27,\"message\":\"Transaction outputs already in utxo set\"}";
+        const UNAVAILABLE_TEXT: &str = "HTTP/1.1 503 Service Unavailable\r\ncontent-type: text/plain\r\ncontent-length: 19\r\nconnection: close\r\n\r\nService Unavailable";
+
+        let (url, requests) = mock_server(vec![ACCEPTED, REJECTED, UNAVAILABLE_TEXT]).await;
+        let client = MempoolClient::new(url);
+
+        // Accepted: the txid is returned as plain text
+        let txid: Txid = client.broadcast_hex(TX_HEX).await.unwrap();
+        assert_eq!(
+            txid,
+            Txid::from_str("f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16")
+                .unwrap()
+        );
+
+        let head: String = requests.lock().unwrap()[0].to_lowercase();
+        assert!(head.starts_with("post /api/tx "));
+        assert!(head.contains("content-type: text/plain\r\n"));
+
+        // Rejected by the node
+        match client.broadcast_hex(TX_HEX).await {
+            Err(Error::Rejected(rejection)) => {
+                assert_eq!(rejection.code, Some(-27));
+                assert_eq!(rejection.message, "Transaction outputs already in utxo set");
+            }
+            res => panic!("unexpected result: {res:?}"),
+        }
+
+        // Other errors
+        assert!(matches!(
+            client.broadcast_hex(TX_HEX).await,
+            Err(Error::Mempool(msg)) if msg == "Service Unavailable"
+        ));
     }
 }
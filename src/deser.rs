@@ -44,6 +44,27 @@ pub mod optional_weight_serde {
     }
 }
 
+#[cfg(feature = "liquid")]
+pub(crate) mod elements_lock_time_serde {
+    use elements::LockTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(lock_time: &LockTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(lock_time.to_consensus_u32())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<LockTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let n = u32::deserialize(deserializer)?;
+        Ok(LockTime::from_consensus(n))
+    }
+}
+
 pub(crate) mod fee_rate_f64_serde {
     use bitcoin::FeeRate;
     use serde::{Deserialize, Deserializer, Serializer};
@@ -66,6 +87,67 @@ pub(crate) mod fee_rate_f64_serde {
     }
 }
 
+pub(crate) mod optional_fee_rate_f64_serde {
+    use bitcoin::FeeRate;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(fee_rate: &Option<FeeRate>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match fee_rate {
+            Some(fee_rate) => serializer.serialize_some(&super::fee_rate_to_f64_sat_vb(fee_rate)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<FeeRate>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let sat_per_vb: Option<f64> = Option::deserialize(deserializer)?;
+        Ok(sat_per_vb.map(super::fee_rate_from_f64_sat_vb))
+    }
+}
+
+/// Deserialize `null` as the default value
+pub(crate) fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + serde::Deserialize<'de>,
+{
+    let value: Option<T> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(value.unwrap_or_default())
+}
+
+pub(crate) mod optional_fee_rate_btc_kvb_serde {
+    use bitcoin::FeeRate;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(fee_rate: &Option<FeeRate>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match fee_rate {
+            // Convert from sat/kwu to BTC/kvB
+            Some(fee_rate) => {
+                serializer.serialize_some(&(fee_rate.to_sat_per_kwu() as f64 * 4.0 / 100_000_000.0))
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<FeeRate>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let btc_per_kvb: Option<f64> = Option::deserialize(deserializer)?;
+        Ok(btc_per_kvb.map(|btc_per_kvb| {
+            FeeRate::from_sat_per_kwu((btc_per_kvb * 100_000_000.0 / 4.0).round() as u64)
+        }))
+    }
+}
+
 pub(crate) mod fee_rate_vec_serde {
     use bitcoin::FeeRate;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -144,3 +226,120 @@ fn fee_rate_to_f64_sat_vb(fee_rate: &FeeRate) -> f64 {
 fn fee_rate_from_f64_sat_vb(sat_per_vb: f64) -> FeeRate {
     FeeRate::from_sat_per_kwu((sat_per_vb * 250.0).round() as u64)
 }
+
+#[cfg(any(feature = "lightning", feature = "liquid"))]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum U64OrString {
+    Number(u64),
+    String(String),
+}
+
+#[cfg(any(feature = "lightning", feature = "liquid"))]
+impl U64OrString {
+    fn parse<E>(self) -> Result<u64, E>
+    where
+        E: serde::de::Error,
+    {
+        match self {
+            Self::Number(n) => Ok(n),
+            Self::String(s) => s.parse().map_err(E::custom),
+        }
+    }
+}
+
+/// Serde module for optional `u64`, that may be encoded as a string
+#[cfg(feature = "lightning")]
+pub(crate) mod optional_u64_str_serde {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::U64OrString;
+
+    pub fn serialize<S>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => serializer.serialize_some(v),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value: Option<U64OrString> = Option::deserialize(deserializer)?;
+        value.map(U64OrString::parse).transpose()
+    }
+}
+
+/// Serde module for Amount, that may be encoded as a string
+#[cfg(any(feature = "lightning", feature = "liquid"))]
+pub(crate) mod amount_str_serde {
+    use bitcoin::Amount;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::U64OrString;
+
+    pub fn serialize<S>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(amount.to_sat())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Amount, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let sat: u64 = U64OrString::deserialize(deserializer)?.parse()?;
+        Ok(Amount::from_sat(sat))
+    }
+}
+
+/// Serde module for optional Amount, that may be encoded as a string
+#[cfg(feature = "lightning")]
+pub(crate) mod optional_amount_str_serde {
+    use bitcoin::Amount;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S>(amount: &Option<Amount>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::optional_u64_str_serde::serialize(&amount.map(|a| a.to_sat()), serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Amount>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let sat: Option<u64> = super::optional_u64_str_serde::deserialize(deserializer)?;
+        Ok(sat.map(Amount::from_sat))
+    }
+}
+
+/// Serde module for optional bool, encoded as `0` or `1`
+#[cfg(feature = "lightning")]
+pub(crate) mod optional_bool_int_serde {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => serializer.serialize_some(&u8::from(*v)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value: Option<u8> = Option::deserialize(deserializer)?;
+        Ok(value.map(|v| v != 0))
+    }
+}
@@ -2,8 +2,124 @@
 
 use std::fmt;
 
+use bitcoin::Network;
+use bitcoin::consensus::encode;
+use bitcoin::hex::HexToArrayError;
+use serde::Deserialize;
 use url::ParseError;
 
+/// Reason why a transaction was rejected by the node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RejectReason {
+    /// Fee is below the minimum relay fee (`min relay fee not met`)
+    MinRelayFeeNotMet,
+    /// Fee is below the current mempool minimum fee (`mempool min fee not met`)
+    MempoolMinFeeNotMet,
+    /// Inputs are missing or already spent (`bad-txns-inputs-missingorspent`)
+    InputsMissingOrSpent,
+    /// Conflicts with a transaction already in the mempool (`txn-mempool-conflict`)
+    MempoolConflict,
+    /// Fee is too low to replace the conflicting transactions (`insufficient fee`)
+    InsufficientFee,
+    /// Transaction is already in the mempool (`txn-already-in-mempool`, `txn-already-known`)
+    AlreadyInMempool,
+    /// Transaction is already confirmed (`transaction already in block chain`)
+    AlreadyInChain,
+    /// Transaction is not final (`non-final`)
+    NonFinal,
+    /// Transaction has dust outputs (`dust`)
+    Dust,
+    /// Fee rate exceeds the maximum allowed by the node (`max-fee-exceeded`)
+    MaxFeeExceeded,
+    /// Any other reason (see [`TransactionRejection::message`])
+    Other,
+}
+
+impl RejectReason {
+    fn from_message(message: &str) -> Self {
+        let message: String = message.to_lowercase();
+
+        if message.contains("min relay fee not met") {
+            Self::MinRelayFeeNotMet
+        } else if message.contains("mempool min fee not met") {
+            Self::MempoolMinFeeNotMet
+        } else if message.contains("bad-txns-inputs-missingorspent")
+            || message.contains("missing-inputs")
+        {
+            Self::InputsMissingOrSpent
+        } else if message.contains("txn-mempool-conflict") {
+            Self::MempoolConflict
+        } else if message.contains("insufficient fee") {
+            Self::InsufficientFee
+        } else if message.contains("txn-already-in-mempool")
+            || message.contains("txn-already-known")
+        {
+            Self::AlreadyInMempool
+        } else if message.contains("already in block chain") {
+            Self::AlreadyInChain
+        } else if message.contains("non-final") {
+            Self::NonFinal
+        } else if message.contains("dust") {
+            Self::Dust
+        } else if message.contains("max-fee-exceeded") || message.contains("fee exceeds maximum") {
+            Self::MaxFeeExceeded
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// Transaction rejected by the node
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransactionRejection {
+    /// RPC error code (if reported)
+    pub code: Option<i32>,
+    /// Rejection reason
+    pub reason: RejectReason,
+    /// Rejection message, as returned by the node
+    pub message: String,
+}
+
+impl fmt::Display for TransactionRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "Transaction rejected ({code}): {}", self.message),
+            None => write!(f, "Transaction rejected: {}", self.message),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    code: i32,
+    message: String,
+}
+
+impl TransactionRejection {
+    /// Parse the rejection from the body of a failed broadcast.
+    ///
+    /// The body usually looks like `sendrawtransaction RPC error: {"code":-26,"message":"..."}`.
+    pub(crate) fn parse(body: &str) -> Self {
+        let body: &str = body.trim();
+
+        // Try to extract the JSON RPC error
+        let rpc_error: Option<RpcError> = body
+            .find('{')
+            .and_then(|start| serde_json::from_str(&body[start..]).ok());
+
+        let (code, message) = match rpc_error {
+            Some(RpcError { code, message }) => (Some(code), message),
+            None => (None, body.to_string()),
+        };
+
+        Self {
+            code,
+            reason: RejectReason::from_message(&message),
+            message,
+        }
+    }
+}
+
 /// Mempool error
 #[derive(Debug)]
 pub enum Error {
@@ -13,6 +129,24 @@ pub enum Error {
     Reqwest(reqwest::Error),
     /// Mempool response error
     Mempool(String),
+    /// Consensus decoding error
+    Consensus(encode::Error),
+    /// Consensus hex decoding error
+    ConsensusHex(encode::FromHexError),
+    /// Hex decoding error
+    Hex(HexToArrayError),
+    /// Transaction rejected by the node
+    Rejected(TransactionRejection),
+    /// Address not valid for the network of the client
+    NetworkMismatch {
+        /// Network of the client
+        expected: Network,
+        /// Address
+        address: String,
+    },
+    /// Elements consensus decoding error
+    #[cfg(feature = "liquid")]
+    Elements(elements::encode::Error),
     /// Tungstenite error
     #[cfg(feature = "ws")]
     Tungstenite(tokio_tungstenite::tungstenite::Error),
@@ -35,6 +169,15 @@ impl fmt::Display for Error {
             Self::Url(e) => write!(f, "{e}"),
             Self::Reqwest(e) => write!(f, "{e}"),
             Self::Mempool(e) => write!(f, "{e}"),
+            Self::Consensus(e) => write!(f, "{e}"),
+            Self::ConsensusHex(e) => write!(f, "{e}"),
+            Self::Hex(e) => write!(f, "{e}"),
+            Self::Rejected(e) => write!(f, "{e}"),
+            Self::NetworkMismatch { expected, address } => {
+                write!(f, "Address {address} is not valid for network {expected}")
+            }
+            #[cfg(feature = "liquid")]
+            Self::Elements(e) => write!(f, "{e}"),
             #[cfg(feature = "ws")]
             Self::Tungstenite(e) => write!(f, "{e}"),
             #[cfg(feature = "ws")]
@@ -59,6 +202,31 @@ impl From<reqwest::Error> for Error {
     }
 }
 
+impl From<encode::Error> for Error {
+    fn from(e: encode::Error) -> Self {
+        Self::Consensus(e)
+    }
+}
+
+impl From<encode::FromHexError> for Error {
+    fn from(e: encode::FromHexError) -> Self {
+        Self::ConsensusHex(e)
+    }
+}
+
+impl From<HexToArrayError> for Error {
+    fn from(e: HexToArrayError) -> Self {
+        Self::Hex(e)
+    }
+}
+
+#[cfg(feature = "liquid")]
+impl From<elements::encode::Error> for Error {
+    fn from(e: elements::encode::Error) -> Self {
+        Self::Elements(e)
+    }
+}
+
 #[cfg(feature = "ws")]
 impl From<tokio_tungstenite::tungstenite::Error> for Error {
     fn from(e: tokio_tungstenite::tungstenite::Error) -> Self {
@@ -72,3 +240,45 @@ impl From<serde_json::Error> for Error {
         Self::Json(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rpc_rejection() {
+        let body = r#"sendrawtransaction RPC error: {"code":-26,"message":"min relay fee not met, 100 < 141"}"#;
+        let rejection = TransactionRejection::parse(body);
+
+        assert_eq!(
+            rejection,
+            TransactionRejection {
+                code: Some(-26),
+                reason: RejectReason::MinRelayFeeNotMet,
+                message: "min relay fee not met, 100 < 141".to_string(),
+            }
+        );
+
+        let body = r#"sendrawtransaction RPC error: {"code":-25,"message":"bad-txns-inputs-missingorspent"}"#;
+        let rejection = TransactionRejection::parse(body);
+        assert_eq!(rejection.code, Some(-25));
+        assert_eq!(rejection.reason, RejectReason::InputsMissingOrSpent);
+    }
+
+    #[test]
+    fn test_parse_plain_rejection() {
+        let rejection = TransactionRejection::parse("txn-mempool-conflict\n");
+
+        assert_eq!(
+            rejection,
+            TransactionRejection {
+                code: None,
+                reason: RejectReason::MempoolConflict,
+                message: "txn-mempool-conflict".to_string(),
+            }
+        );
+
+        let rejection = TransactionRejection::parse("something unexpected");
+        assert_eq!(rejection.reason, RejectReason::Other);
+    }
+}
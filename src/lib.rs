@@ -5,11 +5,17 @@
 #![warn(clippy::large_futures)]
 #![warn(rustdoc::bare_urls)]
 
+pub mod accelerator;
 pub mod builder;
 pub mod client;
 mod deser;
 pub mod error;
+#[cfg(feature = "lightning")]
+pub mod lightning;
+#[cfg(feature = "liquid")]
+pub mod liquid;
 pub mod prelude;
 pub mod response;
+pub mod retry;
 #[cfg(feature = "ws")]
 pub mod websocket;
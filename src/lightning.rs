@@ -0,0 +1,874 @@
+//! Lightning Network
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use bitcoin::secp256k1::PublicKey;
+use bitcoin::{Amount, Txid};
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use url::Url;
+
+use crate::client::MempoolClient;
+use crate::deser;
+use crate::error::Error;
+
+/// Short channel ID parse error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseShortChannelIdError;
+
+impl std::error::Error for ParseShortChannelIdError {}
+
+impl fmt::Display for ParseShortChannelIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid short channel ID")
+    }
+}
+
+/// Short channel ID
+///
+/// Encodes the position of the funding output in the chain: block height, transaction index and output index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShortChannelId(u64);
+
+impl ShortChannelId {
+    /// Construct from the position of the funding output
+    ///
+    /// Returns `None` if `block_height` or `tx_index` don't fit in 24 bits.
+    pub fn new(block_height: u32, tx_index: u32, output_index: u16) -> Option<Self> {
+        if block_height > 0xFF_FFFF || tx_index > 0xFF_FFFF {
+            return None;
+        }
+
+        Some(Self(
+            ((block_height as u64) << 40) | ((tx_index as u64) << 16) | output_index as u64,
+        ))
+    }
+
+    /// Construct from the `u64` representation
+    #[inline]
+    pub fn from_u64(scid: u64) -> Self {
+        Self(scid)
+    }
+
+    /// Get the `u64` representation
+    #[inline]
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Height of the block that includes the funding transaction
+    #[inline]
+    pub fn block_height(&self) -> u32 {
+        (self.0 >> 40) as u32
+    }
+
+    /// Index of the funding transaction in the block
+    #[inline]
+    pub fn tx_index(&self) -> u32 {
+        ((self.0 >> 16) & 0xFF_FFFF) as u32
+    }
+
+    /// Index of the funding output in the transaction
+    #[inline]
+    pub fn output_index(&self) -> u16 {
+        (self.0 & 0xFFFF) as u16
+    }
+}
+
+impl fmt::Display for ShortChannelId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}x{}x{}",
+            self.block_height(),
+            self.tx_index(),
+            self.output_index()
+        )
+    }
+}
+
+impl FromStr for ShortChannelId {
+    type Err = ParseShortChannelIdError;
+
+    /// Parse either the `BLOCKxTXxOUTPUT` or the `u64` representation
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split('x');
+
+        match (split.next(), split.next(), split.next(), split.next()) {
+            (Some(scid), None, None, None) => {
+                scid.parse().map(Self).map_err(|_| ParseShortChannelIdError)
+            }
+            (Some(block_height), Some(tx_index), Some(output_index), None) => {
+                let block_height: u32 =
+                    block_height.parse().map_err(|_| ParseShortChannelIdError)?;
+                let tx_index: u32 = tx_index.parse().map_err(|_| ParseShortChannelIdError)?;
+                let output_index: u16 =
+                    output_index.parse().map_err(|_| ParseShortChannelIdError)?;
+                Self::new(block_height, tx_index, output_index).ok_or(ParseShortChannelIdError)
+            }
+            _ => Err(ParseShortChannelIdError),
+        }
+    }
+}
+
+impl Serialize for ShortChannelId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ShortChannelId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ShortChannelIdVisitor;
+
+        impl Visitor<'_> for ShortChannelIdVisitor {
+            type Value = ShortChannelId;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a short channel ID")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(ShortChannelId(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                ShortChannelId::from_str(v).map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_any(ShortChannelIdVisitor)
+    }
+}
+
+/// Lightning Network statistics snapshot
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LightningNetworkStats {
+    /// Snapshot ID
+    pub id: u64,
+    /// Snapshot date, as an ISO 8601 string
+    pub added: String,
+    /// Number of channels
+    pub channel_count: u32,
+    /// Number of nodes
+    pub node_count: u32,
+    /// Total network capacity
+    pub total_capacity: Amount,
+    /// Number of Tor-only nodes
+    pub tor_nodes: u32,
+    /// Number of clearnet-only nodes
+    pub clearnet_nodes: u32,
+    /// Number of nodes reachable both on clearnet and Tor
+    pub clearnet_tor_nodes: u32,
+    /// Number of nodes without an announced address
+    pub unannounced_nodes: u32,
+    /// Average channel capacity
+    pub avg_capacity: Amount,
+    /// Average fee rate (ppm)
+    pub avg_fee_rate: u64,
+    /// Average base fee (msat)
+    pub avg_base_fee_mtokens: u64,
+    /// Median channel capacity
+    pub med_capacity: Amount,
+    /// Median fee rate (ppm)
+    pub med_fee_rate: u64,
+    /// Median base fee (msat)
+    pub med_base_fee_mtokens: u64,
+}
+
+/// Lightning Network statistics
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LightningStatistics {
+    /// Latest snapshot
+    pub latest: LightningNetworkStats,
+    /// Previous snapshot
+    pub previous: LightningNetworkStats,
+}
+
+/// Lightning node
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct LightningNode {
+    /// Node public key
+    pub public_key: PublicKey,
+    /// Node alias
+    pub alias: String,
+    /// UNIX timestamp of when the node was first seen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_seen: Option<u64>,
+    /// UNIX timestamp of the last node announcement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<u64>,
+    /// Node color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Comma-separated list of announced addresses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sockets: Option<String>,
+    /// Autonomous system number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as_number: Option<u32>,
+    /// Autonomous system organization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as_organization: Option<String>,
+    /// Country ISO code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iso_code: Option<String>,
+    /// City names, keyed by language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<BTreeMap<String, String>>,
+    /// Country names, keyed by language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<BTreeMap<String, String>>,
+    /// Subdivision names, keyed by language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subdivision: Option<BTreeMap<String, String>>,
+    /// Latitude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    /// Longitude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    /// Number of active channels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_channel_count: Option<u32>,
+    /// Number of channels ever opened
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opened_channel_count: Option<u32>,
+    /// Number of closed channels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_channel_count: Option<u32>,
+    /// Total capacity of the active channels
+    #[serde(default, with = "deser::optional_amount_str_serde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Amount>,
+    /// Number of active channels (in node listings)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<u32>,
+}
+
+/// Lightning channel status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum ChannelStatus {
+    /// Inactive
+    Inactive,
+    /// Active
+    Active,
+    /// Closed
+    Closed,
+}
+
+impl TryFrom<u8> for ChannelStatus {
+    type Error = String;
+
+    fn try_from(status: u8) -> Result<Self, Self::Error> {
+        match status {
+            0 => Ok(Self::Inactive),
+            1 => Ok(Self::Active),
+            2 => Ok(Self::Closed),
+            s => Err(format!("Unknown channel status: {s}")),
+        }
+    }
+}
+
+impl From<ChannelStatus> for u8 {
+    fn from(status: ChannelStatus) -> Self {
+        match status {
+            ChannelStatus::Inactive => 0,
+            ChannelStatus::Active => 1,
+            ChannelStatus::Closed => 2,
+        }
+    }
+}
+
+/// Channel endpoint, with the routing policy announced by the node
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ChannelNode {
+    /// Node public key
+    pub public_key: PublicKey,
+    /// Node alias
+    pub alias: String,
+    /// Proportional fee rate (ppm)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_rate: Option<u64>,
+    /// Base fee (msat)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_fee_mtokens: Option<u64>,
+    /// CLTV expiry delta
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cltv_delta: Option<u16>,
+    /// Minimum HTLC (msat)
+    #[serde(default, with = "deser::optional_u64_str_serde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_htlc_mtokens: Option<u64>,
+    /// Maximum HTLC (msat)
+    #[serde(default, with = "deser::optional_u64_str_serde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_htlc_mtokens: Option<u64>,
+    /// Whether the node disabled the channel
+    #[serde(default, with = "deser::optional_bool_int_serde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_disabled: Option<bool>,
+    /// Number of channels of the node
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<u32>,
+    /// Total capacity of the node
+    #[serde(default, with = "deser::optional_amount_str_serde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Amount>,
+}
+
+/// Lightning channel
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct LightningChannel {
+    /// Short channel ID
+    pub id: ShortChannelId,
+    /// Channel capacity
+    pub capacity: Amount,
+    /// Funding transaction ID
+    pub transaction_id: Txid,
+    /// Funding output index
+    pub transaction_vout: u32,
+    /// Closing transaction ID (if closed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closing_transaction_id: Option<Txid>,
+    /// Closing reason (if closed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closing_reason: Option<u8>,
+    /// Channel status
+    pub status: ChannelStatus,
+    /// Channel creation date, as an ISO 8601 string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// Last channel update date, as an ISO 8601 string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// First endpoint
+    pub node_left: ChannelNode,
+    /// Second endpoint
+    pub node_right: ChannelNode,
+}
+
+/// Ranked Lightning node
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct RankedNode {
+    /// Node public key
+    #[serde(rename = "publicKey")]
+    pub public_key: PublicKey,
+    /// Node alias
+    pub alias: String,
+    /// Total capacity (ranking by capacity)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Amount>,
+    /// Number of channels (ranking by channels)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<u32>,
+}
+
+/// Lightning nodes rankings
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct NodeRankings {
+    /// Top nodes by capacity
+    #[serde(rename = "topByCapacity")]
+    pub top_by_capacity: Vec<RankedNode>,
+    /// Top nodes by number of channels
+    #[serde(rename = "topByChannels")]
+    pub top_by_channels: Vec<RankedNode>,
+}
+
+/// Node search result
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct NodeSearchResult {
+    /// Node public key
+    pub public_key: PublicKey,
+    /// Node alias
+    pub alias: String,
+    /// Total capacity
+    #[serde(default, with = "deser::optional_amount_str_serde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Amount>,
+    /// Number of channels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<u32>,
+}
+
+/// Channel search result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ChannelSearchResult {
+    /// Short channel ID
+    pub id: ShortChannelId,
+}
+
+/// Lightning search results
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LightningSearchResults {
+    /// Matching nodes
+    pub nodes: Vec<NodeSearchResult>,
+    /// Matching channels
+    pub channels: Vec<ChannelSearchResult>,
+}
+
+/// Lightning nodes statistics for a country
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct CountryNodeStats {
+    /// Country names, keyed by language
+    pub name: BTreeMap<String, String>,
+    /// Country ISO code
+    pub iso: String,
+    /// Number of nodes
+    pub count: u32,
+    /// Share of the nodes (percentage)
+    pub share: f64,
+    /// Total capacity of the nodes
+    #[serde(with = "deser::amount_str_serde")]
+    pub capacity: Amount,
+}
+
+/// Lightning nodes in a country
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct CountryNodes {
+    /// Country names, keyed by language
+    pub country: BTreeMap<String, String>,
+    /// Nodes
+    pub nodes: Vec<LightningNode>,
+}
+
+/// Lightning nodes hosted by an ISP
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct IspNodes {
+    /// ISP name
+    pub isp: String,
+    /// Nodes
+    pub nodes: Vec<LightningNode>,
+}
+
+/// ISP entry of the ranking, as returned by the API:
+/// `[comma-separated ASNs, name, capacity, channels, nodes]`
+#[derive(Clone, Serialize, Deserialize)]
+struct IspStatsResponse(String, String, u64, u32, u32);
+
+impl From<IspStats> for IspStatsResponse {
+    fn from(isp: IspStats) -> Self {
+        let asns: Vec<String> = isp.asns.iter().map(|asn| asn.to_string()).collect();
+        Self(
+            asns.join(","),
+            isp.name,
+            isp.capacity.to_sat(),
+            isp.channels,
+            isp.nodes,
+        )
+    }
+}
+
+impl TryFrom<IspStatsResponse> for IspStats {
+    type Error = String;
+
+    fn try_from(
+        IspStatsResponse(asns, name, capacity, channels, nodes): IspStatsResponse,
+    ) -> Result<Self, Self::Error> {
+        let asns: Vec<u32> = asns
+            .split(',')
+            .map(|asn| {
+                asn.trim()
+                    .parse()
+                    .map_err(|_| format!("Invalid ASN: {asn}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            asns,
+            name,
+            capacity: Amount::from_sat(capacity),
+            channels,
+            nodes,
+        })
+    }
+}
+
+/// ISP (Internet Service Provider) statistics
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "IspStatsResponse", into = "IspStatsResponse")]
+pub struct IspStats {
+    /// Autonomous system numbers of the ISP
+    pub asns: Vec<u32>,
+    /// ISP name
+    pub name: String,
+    /// Total capacity of the nodes hosted by the ISP
+    pub capacity: Amount,
+    /// Number of channels of the nodes hosted by the ISP
+    pub channels: u32,
+    /// Number of nodes hosted by the ISP
+    pub nodes: u32,
+}
+
+/// ISP ranking
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct IspRanking {
+    /// Total capacity of the clearnet nodes
+    #[serde(rename = "clearnetCapacity")]
+    pub clearnet_capacity: Amount,
+    /// Total capacity of the nodes with an unknown ISP
+    #[serde(rename = "unknownCapacity")]
+    pub unknown_capacity: Amount,
+    /// Total capacity of the Tor nodes
+    #[serde(rename = "torCapacity")]
+    pub tor_capacity: Amount,
+    /// ISPs, sorted by capacity
+    #[serde(rename = "ispRanking")]
+    pub isp_ranking: Vec<IspStats>,
+}
+
+/// Channel opened or closed by a transaction
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TransactionChannel {
+    /// Short channel ID
+    pub id: ShortChannelId,
+    /// Channel capacity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Amount>,
+    /// Channel status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ChannelStatus>,
+    /// Closing transaction ID (if closed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closing_transaction_id: Option<Txid>,
+}
+
+/// Channels related to a transaction
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TransactionChannels {
+    /// Channels closed by the transaction, keyed by input index
+    #[serde(default)]
+    pub inputs: BTreeMap<u32, TransactionChannel>,
+    /// Channels opened by the transaction, keyed by output index
+    #[serde(default)]
+    pub outputs: BTreeMap<u32, TransactionChannel>,
+}
+
+/// Lightning Network client
+///
+/// Construct it with [`MempoolClient::lightning`].
+#[derive(Debug, Clone, Copy)]
+pub struct LightningClient<'a> {
+    client: &'a MempoolClient,
+}
+
+impl MempoolClient {
+    /// Get the Lightning Network client
+    #[inline]
+    pub fn lightning(&self) -> LightningClient<'_> {
+        LightningClient { client: self }
+    }
+}
+
+impl LightningClient<'_> {
+    /// Get the latest Lightning Network statistics.
+    pub async fn get_statistics(&self) -> Result<LightningStatistics, Error> {
+        let url: Url = self
+            .client
+            .endpoint("/api/v1/lightning/statistics/latest")?;
+        self.client.get_response(url).await
+    }
+
+    /// Get details about a node.
+    pub async fn get_node(&self, public_key: &PublicKey) -> Result<LightningNode, Error> {
+        let url: Url = self
+            .client
+            .endpoint("/api/v1/lightning/nodes/")?
+            .join(public_key.to_string().as_str())?;
+        self.client.get_response(url).await
+    }
+
+    /// Get details about a channel.
+    pub async fn get_channel(&self, id: ShortChannelId) -> Result<LightningChannel, Error> {
+        let url: Url = self
+            .client
+            .endpoint("/api/v1/lightning/channels/")?
+            .join(id.as_u64().to_string().as_str())?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the top nodes by capacity and by number of channels.
+    pub async fn get_node_rankings(&self) -> Result<NodeRankings, Error> {
+        let url: Url = self.client.endpoint("/api/v1/lightning/nodes/rankings")?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the number of nodes and their capacity per country.
+    pub async fn get_nodes_per_country(&self) -> Result<Vec<CountryNodeStats>, Error> {
+        let url: Url = self.client.endpoint("/api/v1/lightning/nodes/countries")?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the nodes located in a country.
+    ///
+    /// `country` is the ISO 3166-1 alpha-2 code (e.g., `US`).
+    pub async fn get_nodes_in_country(&self, country: &str) -> Result<CountryNodes, Error> {
+        let url: Url = self
+            .client
+            .endpoint_with_segments("/api/v1/lightning/nodes/country", &[country])?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the ISPs hosting nodes, ranked by capacity.
+    pub async fn get_isp_ranking(&self) -> Result<IspRanking, Error> {
+        let url: Url = self
+            .client
+            .endpoint("/api/v1/lightning/nodes/isp-ranking")?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the nodes hosted by an ISP, identified by its autonomous system number.
+    pub async fn get_nodes_per_isp(&self, asn: u32) -> Result<IspNodes, Error> {
+        let url: Url = self
+            .client
+            .endpoint("/api/v1/lightning/nodes/isp/")?
+            .join(asn.to_string().as_str())?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the channels opened or closed by some transactions.
+    ///
+    /// The results are returned in the same order as `txids`.
+    pub async fn get_channels_from_txids(
+        &self,
+        txids: &[Txid],
+    ) -> Result<Vec<TransactionChannels>, Error> {
+        let mut url: Url = self.client.endpoint("/api/v1/lightning/channels/txids")?;
+
+        {
+            let mut query = url.query_pairs_mut();
+            for txid in txids.iter() {
+                query.append_pair("txId[]", &txid.to_string());
+            }
+        }
+
+        self.client.get_response(url).await
+    }
+
+    /// Search nodes and channels by alias, public key or short channel ID.
+    pub async fn search(&self, query: &str) -> Result<LightningSearchResults, Error> {
+        let mut url: Url = self.client.endpoint("/api/v1/lightning/search")?;
+        url.query_pairs_mut().append_pair("searchText", query);
+        self.client.get_response(url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_channel_id() {
+        let scid = ShortChannelId::new(699362, 1612, 1).unwrap();
+        assert_eq!(scid.block_height(), 699362);
+        assert_eq!(scid.tx_index(), 1612);
+        assert_eq!(scid.output_index(), 1);
+        assert_eq!(scid.as_u64(), 768956651130322945);
+        assert_eq!(scid.to_string(), "699362x1612x1");
+
+        // Parse
+        assert_eq!(ShortChannelId::from_str("699362x1612x1"), Ok(scid));
+        assert_eq!(ShortChannelId::from_str("768956651130322945"), Ok(scid));
+        assert!(ShortChannelId::from_str("699362x1612").is_err());
+        assert!(ShortChannelId::from_str("16777216x0x0").is_err());
+
+        // Out of range
+        assert_eq!(ShortChannelId::new(0x100_0000, 0, 0), None);
+    }
+
+    #[test]
+    fn test_channel_deserialization() {
+        let json = r#"{
+  "id": "768956651130322945",
+  "short_id": "699362x1612x1",
+  "capacity": 1000000,
+  "transaction_id": "4e5a2b0ec3f8ad0b2a2d62e1c3c4bed3e7d95f3f9a1c0a2b6c7d8e9f00112233",
+  "transaction_vout": 1,
+  "closing_transaction_id": null,
+  "closing_reason": null,
+  "updated_at": "2024-04-19T22:08:31.000Z",
+  "created": "2021-09-01T10:00:00.000Z",
+  "status": 1,
+  "node_left": {
+    "alias": "ACINQ",
+    "public_key": "03864ef025fde8fb587d989186ce6a4a186895ee44a926bfc370e2c366597a3f8f",
+    "channels": 2908,
+    "capacity": "38900000000",
+    "base_fee_mtokens": 1000,
+    "cltv_delta": 144,
+    "fee_rate": 100,
+    "is_disabled": 0,
+    "max_htlc_mtokens": "990000000",
+    "min_htlc_mtokens": 1,
+    "updated_at": "2024-04-19T22:08:31.000Z"
+  },
+  "node_right": {
+    "alias": "bfx-lnd0",
+    "public_key": "033d8656219478701227199cbd6f670335c8d408a92ae88b962c49d4dc0e83e025",
+    "channels": 1010,
+    "capacity": 12000000000,
+    "base_fee_mtokens": 0,
+    "cltv_delta": 40,
+    "fee_rate": 1,
+    "is_disabled": 1,
+    "max_htlc_mtokens": 990000000,
+    "min_htlc_mtokens": "1000",
+    "updated_at": "2024-04-19T22:08:31.000Z"
+  }
+}"#;
+
+        let channel: LightningChannel = serde_json::from_str(json).unwrap();
+
+        assert_eq!(channel.id, ShortChannelId::new(699362, 1612, 1).unwrap());
+        assert_eq!(channel.capacity, Amount::from_sat(1000000));
+        assert_eq!(channel.transaction_vout, 1);
+        assert_eq!(channel.closing_transaction_id, None);
+        assert_eq!(channel.status, ChannelStatus::Active);
+
+        assert_eq!(channel.node_left.alias, "ACINQ");
+        assert_eq!(
+            channel.node_left.capacity,
+            Some(Amount::from_sat(38900000000))
+        );
+        assert_eq!(channel.node_left.max_htlc_mtokens, Some(990000000));
+        assert_eq!(channel.node_left.is_disabled, Some(false));
+
+        assert_eq!(
+            channel.node_right.capacity,
+            Some(Amount::from_sat(12000000000))
+        );
+        assert_eq!(channel.node_right.min_htlc_mtokens, Some(1000));
+        assert_eq!(channel.node_right.is_disabled, Some(true));
+    }
+
+    #[test]
+    fn test_statistics_deserialization() {
+        let json = r#"{"latest":{"id":1011,"added":"2024-04-20T00:00:00.000Z","channel_count":51420,"node_count":12861,"total_capacity":503476294212,"tor_nodes":8562,"clearnet_nodes":1816,"clearnet_tor_nodes":1342,"unannounced_nodes":1141,"avg_capacity":9791449,"avg_fee_rate":602,"avg_base_fee_mtokens":856,"med_capacity":3000000,"med_fee_rate":80,"med_base_fee_mtokens":1000},"previous":{"id":1004,"added":"2024-04-13T00:00:00.000Z","channel_count":51745,"node_count":12927,"total_capacity":502711154183,"tor_nodes":8609,"clearnet_nodes":1823,"clearnet_tor_nodes":1347,"unannounced_nodes":1148,"avg_capacity":9715164,"avg_fee_rate":604,"avg_base_fee_mtokens":855,"med_capacity":3000000,"med_fee_rate":80,"med_base_fee_mtokens":1000}}"#;
+
+        let stats: LightningStatistics = serde_json::from_str(json).unwrap();
+
+        assert_eq!(stats.latest.id, 1011);
+        assert_eq!(stats.latest.channel_count, 51420);
+        assert_eq!(stats.latest.total_capacity, Amount::from_sat(503476294212));
+        assert_eq!(stats.latest.clearnet_tor_nodes, 1342);
+        assert_eq!(stats.latest.med_capacity, Amount::from_sat(3000000));
+        assert_eq!(stats.previous.added, "2024-04-13T00:00:00.000Z");
+        assert_eq!(stats.previous.node_count, 12927);
+        assert_eq!(stats.previous.avg_base_fee_mtokens, 855);
+
+        // Serialize
+        let serialized = serde_json::to_string(&stats).unwrap();
+        assert_eq!(serialized, json);
+    }
+
+    #[test]
+    fn test_node_rankings_deserialization() {
+        let json = r#"{"topByCapacity":[{"publicKey":"033d8656219478701227199cbd6f670335c8d408a92ae88b962c49d4dc0e83e025","alias":"bfx-lnd0","capacity":12000000000}],"topByChannels":[{"publicKey":"03864ef025fde8fb587d989186ce6a4a186895ee44a926bfc370e2c366597a3f8f","alias":"ACINQ","channels":2908}]}"#;
+
+        let rankings: NodeRankings = serde_json::from_str(json).unwrap();
+
+        let node: &RankedNode = &rankings.top_by_capacity[0];
+        assert_eq!(
+            node.public_key,
+            PublicKey::from_str(
+                "033d8656219478701227199cbd6f670335c8d408a92ae88b962c49d4dc0e83e025"
+            )
+            .unwrap()
+        );
+        assert_eq!(node.capacity, Some(Amount::from_sat(12000000000)));
+        assert_eq!(node.channels, None);
+
+        let node: &RankedNode = &rankings.top_by_channels[0];
+        assert_eq!(node.alias, "ACINQ");
+        assert_eq!(node.capacity, None);
+        assert_eq!(node.channels, Some(2908));
+
+        // Serialize
+        let serialized = serde_json::to_string(&rankings).unwrap();
+        assert_eq!(serialized, json);
+    }
+
+    #[test]
+    fn test_isp_ranking_deserialization() {
+        let json = r#"{"clearnetCapacity":417149291318,"unknownCapacity":10528821603,"torCapacity":1086208302412,"ispRanking":[["14061","DigitalOcean",43744004312,6352,382],["16509,14618","Amazon.com",18637898473,2934,396]]}"#;
+
+        let ranking: IspRanking = serde_json::from_str(json).unwrap();
+
+        assert_eq!(ranking.clearnet_capacity, Amount::from_sat(417149291318));
+        assert_eq!(ranking.isp_ranking.len(), 2);
+        assert_eq!(
+            ranking.isp_ranking[1],
+            IspStats {
+                asns: vec![16509, 14618],
+                name: "Amazon.com".to_string(),
+                capacity: Amount::from_sat(18637898473),
+                channels: 2934,
+                nodes: 396,
+            }
+        );
+
+        // Serialize
+        let serialized = serde_json::to_string(&ranking).unwrap();
+        assert_eq!(serialized, json);
+    }
+
+    #[test]
+    fn test_country_nodes_deserialization() {
+        let json = r#"[{"name":{"en":"United States","fr":"États-Unis"},"iso":"US","count":2775,"share":26.91,"capacity":"1413541838016"}]"#;
+        let countries: Vec<CountryNodeStats> = serde_json::from_str(json).unwrap();
+        assert_eq!(countries[0].iso, "US");
+        assert_eq!(countries[0].name.get("en").unwrap(), "United States");
+        assert_eq!(countries[0].capacity, Amount::from_sat(1413541838016));
+
+        let json = r#"{"country":{"en":"United States"},"nodes":[{"public_key":"03864ef025fde8fb587d989186ce6a4a186895ee44a926bfc370e2c366597a3f8f","capacity":"38900000000","channels":2908,"alias":"ACINQ","first_seen":1522941222,"updated_at":1713565800,"city":{"en":"Ashburn"},"country":{"en":"United States"},"iso_code":"US","subdivision":{"en":"Virginia"}}]}"#;
+        let nodes: CountryNodes = serde_json::from_str(json).unwrap();
+        assert_eq!(nodes.nodes.len(), 1);
+        assert_eq!(nodes.nodes[0].channels, Some(2908));
+        assert_eq!(nodes.nodes[0].iso_code.as_deref(), Some("US"));
+    }
+
+    #[test]
+    fn test_transaction_channels_deserialization() {
+        let json = r#"[{"inputs":{},"outputs":{"1":{"id":"768956651130322945","short_id":"699362x1612x1","capacity":1000000,"status":1}}}]"#;
+
+        let channels: Vec<TransactionChannels> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(channels.len(), 1);
+        assert!(channels[0].inputs.is_empty());
+        let channel = channels[0].outputs.get(&1).unwrap();
+        assert_eq!(channel.id, ShortChannelId::new(699362, 1612, 1).unwrap());
+        assert_eq!(channel.status, Some(ChannelStatus::Active));
+    }
+
+    #[test]
+    fn test_search_results_deserialization() {
+        let json = r#"{"nodes":[{"public_key":"03864ef025fde8fb587d989186ce6a4a186895ee44a926bfc370e2c366597a3f8f","alias":"ACINQ","capacity":38900000000,"channels":2908,"status":1}],"channels":[{"short_id":"699362x1612x1","id":"768956651130322945"}]}"#;
+
+        let results: LightningSearchResults = serde_json::from_str(json).unwrap();
+
+        assert_eq!(results.nodes.len(), 1);
+        assert_eq!(results.nodes[0].alias, "ACINQ");
+        assert_eq!(results.nodes[0].channels, Some(2908));
+        assert_eq!(
+            results.channels,
+            vec![ChannelSearchResult {
+                id: ShortChannelId::new(699362, 1612, 1).unwrap(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,650 @@
+//! Liquid Network
+
+use std::str::FromStr;
+
+use bitcoin::address::{Address as BitcoinAddress, NetworkUnchecked};
+use bitcoin::{Amount, ScriptBuf, Weight, Witness};
+use elements::{
+    Address, AssetId, BlockHash, LockTime, Script, Sequence, Transaction, TxMerkleNode, Txid,
+    encode,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::client::MempoolClient;
+use crate::deser;
+use crate::error::Error;
+
+/// Liquid network
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LiquidNetwork {
+    /// Liquid mainnet
+    #[default]
+    Liquid,
+    /// Liquid testnet
+    LiquidTestnet,
+}
+
+impl LiquidNetwork {
+    /// Path prefix of the network on liquid.network (e.g., `/liquidtestnet`)
+    fn prefix(&self) -> &str {
+        match self {
+            Self::Liquid => "",
+            Self::LiquidTestnet => "/liquidtestnet",
+        }
+    }
+}
+
+/// Liquid transaction confirmation status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LiquidTransactionStatus {
+    /// Whether the transaction is confirmed
+    pub confirmed: bool,
+    /// Height of the block that includes the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_height: Option<u32>,
+    /// Hash of the block that includes the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<BlockHash>,
+    /// UNIX timestamp of the block that includes the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_time: Option<u64>,
+}
+
+/// Input that issued an asset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AssetIssuanceInput {
+    /// ID of the issuance transaction
+    pub txid: Txid,
+    /// Index of the issuance input
+    pub vin: u32,
+}
+
+/// Output spent by the issuance input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AssetIssuancePrevout {
+    /// ID of the transaction being spent
+    pub txid: Txid,
+    /// Index of the output being spent
+    pub vout: u32,
+}
+
+/// Asset statistics
+///
+/// Issued assets report issuance and burn statistics, while L-BTC reports peg-in and peg-out ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AssetStats {
+    /// Number of transactions
+    pub tx_count: u32,
+    /// Number of issuances
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuance_count: Option<u32>,
+    /// Issued amount, in base units (`None` if blinded)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issued_amount: Option<u64>,
+    /// Burned amount, in base units
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub burned_amount: Option<u64>,
+    /// Whether some issuances are blinded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_blinded_issuances: Option<bool>,
+    /// Number of reissuance tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reissuance_tokens: Option<u64>,
+    /// Number of burned reissuance tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub burned_reissuance_tokens: Option<u64>,
+    /// Number of peg-ins (L-BTC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peg_in_count: Option<u32>,
+    /// Pegged-in amount (L-BTC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peg_in_amount: Option<Amount>,
+    /// Number of peg-outs (L-BTC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peg_out_count: Option<u32>,
+    /// Pegged-out amount (L-BTC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peg_out_amount: Option<Amount>,
+    /// Number of burns (L-BTC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub burn_count: Option<u32>,
+}
+
+/// Asset issuer entity
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AssetEntity {
+    /// Issuer domain
+    pub domain: String,
+}
+
+/// Liquid asset
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LiquidAsset {
+    /// Asset ID
+    pub asset_id: AssetId,
+    /// Input that issued the asset (missing for L-BTC)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuance_txin: Option<AssetIssuanceInput>,
+    /// Output spent by the issuance input (missing for L-BTC)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuance_prevout: Option<AssetIssuancePrevout>,
+    /// Reissuance token ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reissuance_token: Option<AssetId>,
+    /// Contract hash
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_hash: Option<String>,
+    /// Confirmation status of the issuance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<LiquidTransactionStatus>,
+    /// On-chain statistics
+    pub chain_stats: AssetStats,
+    /// Mempool statistics
+    pub mempool_stats: AssetStats,
+    /// Issuer entity (registered assets only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<AssetEntity>,
+    /// Number of decimal places (registered assets only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precision: Option<u8>,
+    /// Name (registered assets only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Ticker (registered assets only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticker: Option<String>,
+}
+
+/// Liquid block information
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LiquidBlock {
+    /// Block hash
+    pub id: BlockHash,
+    /// Block height
+    pub height: u32,
+    /// Block version
+    pub version: u32,
+    /// UNIX timestamp
+    pub timestamp: u64,
+    /// Number of transactions in the block
+    pub tx_count: u32,
+    /// Block size in bytes
+    pub size: u32,
+    /// Block weight
+    pub weight: Weight,
+    /// Merkle root hash
+    pub merkle_root: TxMerkleNode,
+    /// Previous block hash (missing for the genesis block)
+    #[serde(rename = "previousblockhash")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_block_hash: Option<BlockHash>,
+    /// Median time of the block
+    #[serde(rename = "mediantime")]
+    pub median_time: u64,
+}
+
+/// Peg-out data of a Liquid transaction output
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LiquidPegout {
+    /// Hash of the Bitcoin genesis block
+    pub genesis_hash: bitcoin::BlockHash,
+    /// Bitcoin script pubkey
+    #[serde(rename = "scriptpubkey")]
+    pub script_pubkey: ScriptBuf,
+    /// Bitcoin address (if any)
+    #[serde(rename = "scriptpubkey_address")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script_pubkey_address: Option<BitcoinAddress<NetworkUnchecked>>,
+}
+
+/// Liquid transaction output
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LiquidTransactionOutput {
+    /// Script pubkey
+    #[serde(rename = "scriptpubkey")]
+    pub script_pubkey: Script,
+    /// Script pubkey type (e.g., `v0_p2wpkh`, `fee`, `op_return`)
+    #[serde(rename = "scriptpubkey_type")]
+    pub script_pubkey_type: String,
+    /// Unconfidential Liquid address (if any)
+    #[serde(rename = "scriptpubkey_address")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script_pubkey_address: Option<Address>,
+    /// Explicit asset (`None` if blinded)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<AssetId>,
+    /// Explicit value, in base units of the asset (`None` if blinded)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<u64>,
+    /// Asset commitment (blinded outputs only)
+    #[serde(rename = "assetcommitment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_commitment: Option<String>,
+    /// Value commitment (blinded outputs only)
+    #[serde(rename = "valuecommitment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_commitment: Option<String>,
+    /// Peg-out data (peg-out outputs only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pegout: Option<LiquidPegout>,
+}
+
+impl LiquidTransactionOutput {
+    /// Whether the asset and the value are blinded
+    #[inline]
+    pub fn is_blinded(&self) -> bool {
+        self.asset.is_none() || self.value.is_none()
+    }
+}
+
+/// Asset issuance of a Liquid transaction input
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LiquidIssuance {
+    /// ID of the issued asset
+    pub asset_id: AssetId,
+    /// Whether this is a reissuance
+    pub is_reissuance: bool,
+    /// Contract hash
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_hash: Option<String>,
+    /// Explicit issued amount, in base units (`None` if blinded)
+    #[serde(rename = "assetamount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_amount: Option<u64>,
+    /// Explicit amount of issued reissuance tokens (`None` if blinded)
+    #[serde(rename = "tokenamount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_amount: Option<u64>,
+}
+
+/// Liquid transaction input
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LiquidTransactionInput {
+    /// ID of the transaction being spent
+    pub txid: Txid,
+    /// Index of the output being spent
+    pub vout: u32,
+    /// Output being spent (missing for coinbase and peg-in inputs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevout: Option<LiquidTransactionOutput>,
+    /// Script signature
+    #[serde(rename = "scriptsig")]
+    pub script_sig: Script,
+    /// Witness data
+    #[serde(default)]
+    pub witness: Witness,
+    /// Whether this is a coinbase input
+    pub is_coinbase: bool,
+    /// Whether this is a peg-in input
+    #[serde(default)]
+    pub is_pegin: bool,
+    /// Sequence number
+    pub sequence: Sequence,
+    /// Asset issuance (if any)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuance: Option<LiquidIssuance>,
+}
+
+/// Liquid transaction
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LiquidTransaction {
+    /// Transaction ID
+    pub txid: Txid,
+    /// Transaction version
+    pub version: i32,
+    /// Lock time
+    #[serde(with = "deser::elements_lock_time_serde")]
+    pub locktime: LockTime,
+    /// Inputs
+    pub vin: Vec<LiquidTransactionInput>,
+    /// Outputs
+    pub vout: Vec<LiquidTransactionOutput>,
+    /// Transaction size in bytes
+    pub size: u32,
+    /// Transaction weight
+    pub weight: Weight,
+    /// Transaction fee, in L-BTC
+    pub fee: Amount,
+    /// Confirmation status
+    pub status: LiquidTransactionStatus,
+}
+
+/// Total L-BTC pegged in (peg-ins minus peg-outs), or total federation reserves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LiquidPegBalance {
+    /// Amount
+    #[serde(with = "deser::amount_str_serde")]
+    pub amount: Amount,
+    /// Height of the last block processed
+    #[serde(rename = "lastBlockUpdate")]
+    pub last_block_update: u32,
+}
+
+/// Monthly L-BTC peg or federation reserves balance
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LiquidMonthlyBalance {
+    /// Month, as a `YYYY-MM-DD` date
+    pub date: String,
+    /// Amount
+    #[serde(with = "deser::amount_str_serde")]
+    pub amount: Amount,
+}
+
+/// Liquid Network client
+///
+/// Construct it with [`MempoolClient::liquid`] or [`MempoolClient::liquid_network`],
+/// from a client whose base URL is a Liquid explorer (e.g., `https://liquid.network`).
+#[derive(Debug, Clone, Copy)]
+pub struct LiquidClient<'a> {
+    client: &'a MempoolClient,
+    network: LiquidNetwork,
+}
+
+impl MempoolClient {
+    /// Get the Liquid mainnet client
+    #[inline]
+    pub fn liquid(&self) -> LiquidClient<'_> {
+        self.liquid_network(LiquidNetwork::Liquid)
+    }
+
+    /// Get the client of a Liquid network
+    #[inline]
+    pub fn liquid_network(&self, network: LiquidNetwork) -> LiquidClient<'_> {
+        LiquidClient {
+            client: self,
+            network,
+        }
+    }
+}
+
+impl LiquidClient<'_> {
+    /// Get the network the client targets
+    #[inline]
+    pub fn network(&self) -> LiquidNetwork {
+        self.network
+    }
+
+    /// Build the URL of an endpoint of the selected network.
+    fn endpoint(&self, path: &str) -> Result<Url, Error> {
+        let prefix: &str = self.network.prefix();
+        self.client.join_url(&format!("{prefix}{path}"))
+    }
+
+    /// Get the height of the last block.
+    pub async fn get_block_tip_height(&self) -> Result<u32, Error> {
+        let url: Url = self.endpoint("/api/blocks/tip/height")?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the hash of the last block.
+    pub async fn get_block_tip_hash(&self) -> Result<BlockHash, Error> {
+        let url: Url = self.endpoint("/api/blocks/tip/hash")?;
+        let hash: String = self.client.get_text(url).await?;
+        Ok(BlockHash::from_str(hash.trim())?)
+    }
+
+    /// Get the hash of the block at `height` in the best chain.
+    pub async fn get_block_hash(&self, height: u32) -> Result<BlockHash, Error> {
+        let url: Url = self.endpoint(&format!("/api/block-height/{height}"))?;
+        let hash: String = self.client.get_text(url).await?;
+        Ok(BlockHash::from_str(hash.trim())?)
+    }
+
+    /// Get the block information
+    pub async fn get_block(&self, hash: BlockHash) -> Result<LiquidBlock, Error> {
+        let url: Url = self.endpoint(&format!("/api/block/{hash}"))?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the IDs of all the transactions in a block.
+    pub async fn get_block_txids(&self, hash: BlockHash) -> Result<Vec<Txid>, Error> {
+        let url: Url = self.endpoint(&format!("/api/block/{hash}/txids"))?;
+        self.client.get_response(url).await
+    }
+
+    /// Get a transaction.
+    pub async fn get_transaction(&self, txid: Txid) -> Result<LiquidTransaction, Error> {
+        let url: Url = self.endpoint(&format!("/api/tx/{txid}"))?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the confirmation status of a transaction.
+    pub async fn get_transaction_status(
+        &self,
+        txid: Txid,
+    ) -> Result<LiquidTransactionStatus, Error> {
+        let url: Url = self.endpoint(&format!("/api/tx/{txid}/status"))?;
+        self.client.get_response(url).await
+    }
+
+    /// Get a transaction, decoded from its raw binary serialization.
+    pub async fn get_raw_transaction(&self, txid: Txid) -> Result<Transaction, Error> {
+        let url: Url = self.endpoint(&format!("/api/tx/{txid}/raw"))?;
+        let bytes: Vec<u8> = self.client.get_bytes(url).await?;
+        Ok(encode::deserialize(&bytes)?)
+    }
+
+    /// Get information about an asset.
+    pub async fn get_asset(&self, asset_id: AssetId) -> Result<LiquidAsset, Error> {
+        let url: Url = self.endpoint(&format!("/api/asset/{asset_id}"))?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the transactions of an asset (issuances, reissuances and burns, or peg-ins and peg-outs for L-BTC).
+    pub async fn get_asset_transactions(
+        &self,
+        asset_id: AssetId,
+    ) -> Result<Vec<LiquidTransaction>, Error> {
+        let url: Url = self.endpoint(&format!("/api/asset/{asset_id}/txs"))?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the circulating supply of an asset, in base units.
+    pub async fn get_asset_supply(&self, asset_id: AssetId) -> Result<u64, Error> {
+        let url: Url = self.endpoint(&format!("/api/asset/{asset_id}/supply"))?;
+        let supply: String = self.client.get_text(url).await?;
+        supply
+            .trim()
+            .parse()
+            .map_err(|_| Error::Mempool(format!("Invalid asset supply: {supply}")))
+    }
+
+    /// Get the total amount of L-BTC pegged in.
+    pub async fn get_pegs(&self) -> Result<LiquidPegBalance, Error> {
+        let url: Url = self.endpoint("/api/v1/liquid/pegs")?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the amount of L-BTC pegged in at the end of each month.
+    pub async fn get_pegs_per_month(&self) -> Result<Vec<LiquidMonthlyBalance>, Error> {
+        let url: Url = self.endpoint("/api/v1/liquid/pegs/month")?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the total amount of BTC held by the federation.
+    pub async fn get_federation_reserves(&self) -> Result<LiquidPegBalance, Error> {
+        let url: Url = self.endpoint("/api/v1/liquid/reserves")?;
+        self.client.get_response(url).await
+    }
+
+    /// Get the amount of BTC held by the federation at the end of each month.
+    pub async fn get_federation_reserves_per_month(
+        &self,
+    ) -> Result<Vec<LiquidMonthlyBalance>, Error> {
+        let url: Url = self.endpoint("/api/v1/liquid/reserves/month")?;
+        self.client.get_response(url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hex::FromHex;
+
+    use super::*;
+
+    #[test]
+    fn test_network_endpoint() {
+        let client = MempoolClient::new(Url::parse("https://liquid.network").unwrap());
+
+        let liquid = client.liquid();
+        assert_eq!(liquid.network(), LiquidNetwork::Liquid);
+        assert_eq!(
+            liquid.endpoint("/api/v1/liquid/pegs").unwrap().as_str(),
+            "https://liquid.network/api/v1/liquid/pegs"
+        );
+
+        let liquid = client.liquid_network(LiquidNetwork::LiquidTestnet);
+        assert_eq!(
+            liquid.endpoint("/api/blocks/tip/height").unwrap().as_str(),
+            "https://liquid.network/liquidtestnet/api/blocks/tip/height"
+        );
+    }
+
+    #[test]
+    fn test_liquid_asset_deserialization() {
+        // USDt, with its registered contract (issuance and chain statistics omitted)
+        let json = r#"{"asset_id":"ce091c998b83c78bb71a632313ba3760f1763d9cfcffae02258ffa9865a37bd2","issuance_txin":{"txid":"abb4080d91849e933ee2ed65da6b436f7c385cf363fb4aa08399f1e27c58ff3d","vin":0},"issuance_prevout":{"txid":"9596d259270ef5bac0020435e6d859aea633409483ba64e232b8ba04ce288668","vout":0},"reissuance_token":"59fe4d2127ba9f16bd6850a3e6271a166e7ed2e1669f6c107d655791c94ee98f","contract_hash":"3c7f0a53c2ff5b99590620d7f6604a7a3a7bfbaaa6aa61f7bfc7833ca03cde82","chain_stats":{"tx_count":3,"issuance_count":3,"issued_amount":0,"burned_amount":0,"has_blinded_issuances":true,"reissuance_tokens":null,"burned_reissuance_tokens":0},"mempool_stats":{"tx_count":0,"issuance_count":0,"issued_amount":0,"burned_amount":0,"has_blinded_issuances":false,"reissuance_tokens":null,"burned_reissuance_tokens":0},"contract":{"entity":{"domain":"tether.to"},"issuer_pubkey":"0337cceec0beea0232ebe14cba0197a9fbd45fcf2ec946749de920e71434c2b904","name":"Tether USD","precision":8,"ticker":"USDt","version":0},"entity":{"domain":"tether.to"},"precision":8,"name":"Tether USD","ticker":"USDt"}"#;
+
+        let asset: LiquidAsset = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            asset.asset_id,
+            AssetId::from_str("ce091c998b83c78bb71a632313ba3760f1763d9cfcffae02258ffa9865a37bd2")
+                .unwrap()
+        );
+        assert_eq!(asset.issuance_txin.unwrap().vin, 0);
+        assert_eq!(asset.issuance_prevout.unwrap().vout, 0);
+        assert_eq!(asset.status, None);
+        assert_eq!(asset.chain_stats.has_blinded_issuances, Some(true));
+        assert_eq!(asset.chain_stats.reissuance_tokens, None);
+        assert_eq!(asset.entity.unwrap().domain, "tether.to");
+        assert_eq!(asset.precision, Some(8));
+        assert_eq!(asset.ticker.as_deref(), Some("USDt"));
+
+        // L-BTC
+        let json = r#"{"asset_id":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d","chain_stats":{"tx_count":4213,"peg_in_count":3046,"peg_in_amount":689466405469,"peg_out_count":1165,"peg_out_amount":313566385473,"burn_count":2,"burned_amount":100000},"mempool_stats":{"tx_count":0,"peg_in_count":0,"peg_in_amount":0,"peg_out_count":0,"peg_out_amount":0,"burn_count":0,"burned_amount":0}}"#;
+
+        let asset: LiquidAsset = serde_json::from_str(json).unwrap();
+
+        assert_eq!(asset.asset_id, AssetId::LIQUID_BTC);
+        assert_eq!(asset.issuance_txin, None);
+        assert_eq!(
+            asset.chain_stats.peg_in_amount,
+            Some(Amount::from_sat(689466405469))
+        );
+        assert_eq!(asset.chain_stats.peg_out_count, Some(1165));
+        assert_eq!(asset.chain_stats.issuance_count, None);
+    }
+
+    #[test]
+    fn test_liquid_block_deserialization() {
+        // Block 1
+        let json = r#"{"id":"afafbbdfc52a45e51a3b634f391f952f6bdfd14ef74b34925954b4e20d0ad639","height":1,"version":536870912,"timestamp":1538011739,"tx_count":1,"size":2566,"weight":10093,"merkle_root":"f227a39d3dcad0e8b08571357eb5b6cab3b996e506dcf6a6cf6b3f8abd04a8c8","previousblockhash":"1466275836220db2944ca059a3a10ef6fd2ea684b0688d2c379296888a206003","mediantime":1538011739}"#;
+
+        let block: LiquidBlock = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            block.id,
+            BlockHash::from_str("afafbbdfc52a45e51a3b634f391f952f6bdfd14ef74b34925954b4e20d0ad639")
+                .unwrap()
+        );
+        assert_eq!(block.height, 1);
+        assert_eq!(block.tx_count, 1);
+        assert_eq!(block.weight, Weight::from_wu(10093));
+        assert_eq!(
+            block.previous_block_hash,
+            Some(
+                BlockHash::from_str(
+                    "1466275836220db2944ca059a3a10ef6fd2ea684b0688d2c379296888a206003"
+                )
+                .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_liquid_transaction_deserialization() {
+        // Coinbase of block 1
+        let json = r#"{"txid":"f227a39d3dcad0e8b08571357eb5b6cab3b996e506dcf6a6cf6b3f8abd04a8c8","version":2,"locktime":0,"vin":[{"txid":"0000000000000000000000000000000000000000000000000000000000000000","vout":4294967295,"prevout":null,"scriptsig":"510101","scriptsig_asm":"OP_PUSHNUM_1 OP_PUSHBYTES_1 01","witness":["0000000000000000000000000000000000000000000000000000000000000000"],"is_coinbase":true,"sequence":4294967295,"is_pegin":false}],"vout":[{"scriptpubkey":"6a","scriptpubkey_asm":"OP_RETURN","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a04ab22aaee2103ddab289182e938bcdf7b9153d609be24e6ad90f6cf5f47c5a30115494f5d19f921024de8c0b16b880acb3c0613693feefde1d9c9c4f4ec40e6d111b7a0afecd12be3","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_4 ab22aaee OP_PUSHBYTES_33 03ddab289182e938bcdf7b9153d609be24e6ad90f6cf5f47c5a30115494f5d19f9 OP_PUSHBYTES_33 024de8c0b16b880acb3c0613693feefde1d9c9c4f4ec40e6d111b7a0afecd12be3","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a04ab22aaee2103075f118532928c7ef27a77644a12a87fbada3cd94cf67b2d2ae5cb169ddaefa42102882c4fed938b20f3472af337cd7674a99f0aab0ae1803e27e978c52c417ce5e1","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_4 ab22aaee OP_PUSHBYTES_33 03075f118532928c7ef27a77644a12a87fbada3cd94cf67b2d2ae5cb169ddaefa4 OP_PUSHBYTES_33 02882c4fed938b20f3472af337cd7674a99f0aab0ae1803e27e978c52c417ce5e1","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a04ab22aaee2103d8b2ed1813370955cfb8dec24b7c5cb34b13fa4545d9e6d47d8c05af56a2c7d221026392f13fefce606c60adadfe9e729e0af84f5f8cb6a35b76be244351635b38f7","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_4 ab22aaee OP_PUSHBYTES_33 03d8b2ed1813370955cfb8dec24b7c5cb34b13fa4545d9e6d47d8c05af56a2c7d2 OP_PUSHBYTES_33 026392f13fefce606c60adadfe9e729e0af84f5f8cb6a35b76be244351635b38f7","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a04ab22aaee2102b988448e337c15cd6ac82b4737e3e2b5e92947da2f7fa96a81db7f9be3fabeb22102f660c7675a1ed4893df838a5c4c07a287997cbd7dc5d884044b338ed606231bc","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_4 ab22aaee OP_PUSHBYTES_33 02b988448e337c15cd6ac82b4737e3e2b5e92947da2f7fa96a81db7f9be3fabeb2 OP_PUSHBYTES_33 02f660c7675a1ed4893df838a5c4c07a287997cbd7dc5d884044b338ed606231bc","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a04ab22aaee21033fad80bd2b818d1ca8a8d4a25dafcf5e740be07db6788be1f2f15266e3c6805d210253ff3f140ef8f594d54996eab810a82550c79204279920d95681afe699d00da5","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_4 ab22aaee OP_PUSHBYTES_33 033fad80bd2b818d1ca8a8d4a25dafcf5e740be07db6788be1f2f15266e3c6805d OP_PUSHBYTES_33 0253ff3f140ef8f594d54996eab810a82550c79204279920d95681afe699d00da5","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a04ab22aaee210362f0cf4898e44a20472664daed460156976bab5cc8bb8431b206bbafddd230c9210399dadeeedc2cefe9042ffa596c553cad1967cda04de6aa0f9fbd96b6044292e7","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_4 ab22aaee OP_PUSHBYTES_33 0362f0cf4898e44a20472664daed460156976bab5cc8bb8431b206bbafddd230c9 OP_PUSHBYTES_33 0399dadeeedc2cefe9042ffa596c553cad1967cda04de6aa0f9fbd96b6044292e7","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a04ab22aaee2103e2a56e47f41eb83af34fb65c4dfb77ac442b01b5134fd92219bd3f4a999c7de52103306f68bc15a6736eebc88af3846394b580abd4f6c2cd57931245785e2e23bff1","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_4 ab22aaee OP_PUSHBYTES_33 03e2a56e47f41eb83af34fb65c4dfb77ac442b01b5134fd92219bd3f4a999c7de5 OP_PUSHBYTES_33 03306f68bc15a6736eebc88af3846394b580abd4f6c2cd57931245785e2e23bff1","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a04ab22aaee2103a257fdefef1f0ee6303942ae3196c31414c9febcfa835bfa5d90fa5292d23bce2103cfad29bc3215fc86be313239388bc1b10ae034ffc1fe56f7f440d1fa18f97a38","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_4 ab22aaee OP_PUSHBYTES_33 03a257fdefef1f0ee6303942ae3196c31414c9febcfa835bfa5d90fa5292d23bce OP_PUSHBYTES_33 03cfad29bc3215fc86be313239388bc1b10ae034ffc1fe56f7f440d1fa18f97a38","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a24aa21a9ed1575a522edf4ac312792705b33bb331a4c431906692a786e32398d5ef41a1f59","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_36 aa21a9ed1575a522edf4ac312792705b33bb331a4c431906692a786e32398d5ef41a1f59","scriptpubkey_type":"op_return","value":0,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"}],"size":1183,"weight":4561,"fee":0,"status":{"confirmed":true,"block_height":1,"block_hash":"afafbbdfc52a45e51a3b634f391f952f6bdfd14ef74b34925954b4e20d0ad639","block_time":1538011739}}"#;
+        let raw = "0200000001010000000000000000000000000000000000000000000000000000000000000000ffffffff03510101ffffffff0a016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f01000000000000000000016a016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f010000000000000000004a6a04ab22aaee2103ddab289182e938bcdf7b9153d609be24e6ad90f6cf5f47c5a30115494f5d19f921024de8c0b16b880acb3c0613693feefde1d9c9c4f4ec40e6d111b7a0afecd12be3016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f010000000000000000004a6a04ab22aaee2103075f118532928c7ef27a77644a12a87fbada3cd94cf67b2d2ae5cb169ddaefa42102882c4fed938b20f3472af337cd7674a99f0aab0ae1803e27e978c52c417ce5e1016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f010000000000000000004a6a04ab22aaee2103d8b2ed1813370955cfb8dec24b7c5cb34b13fa4545d9e6d47d8c05af56a2c7d221026392f13fefce606c60adadfe9e729e0af84f5f8cb6a35b76be244351635b38f7016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f010000000000000000004a6a04ab22aaee2102b988448e337c15cd6ac82b4737e3e2b5e92947da2f7fa96a81db7f9be3fabeb22102f660c7675a1ed4893df838a5c4c07a287997cbd7dc5d884044b338ed606231bc016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f010000000000000000004a6a04ab22aaee21033fad80bd2b818d1ca8a8d4a25dafcf5e740be07db6788be1f2f15266e3c6805d210253ff3f140ef8f594d54996eab810a82550c79204279920d95681afe699d00da5016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f010000000000000000004a6a04ab22aaee210362f0cf4898e44a20472664daed460156976bab5cc8bb8431b206bbafddd230c9210399dadeeedc2cefe9042ffa596c553cad1967cda04de6aa0f9fbd96b6044292e7016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f010000000000000000004a6a04ab22aaee2103e2a56e47f41eb83af34fb65c4dfb77ac442b01b5134fd92219bd3f4a999c7de52103306f68bc15a6736eebc88af3846394b580abd4f6c2cd57931245785e2e23bff1016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f010000000000000000004a6a04ab22aaee2103a257fdefef1f0ee6303942ae3196c31414c9febcfa835bfa5d90fa5292d23bce2103cfad29bc3215fc86be313239388bc1b10ae034ffc1fe56f7f440d1fa18f97a38016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f01000000000000000000266a24aa21a9ed1575a522edf4ac312792705b33bb331a4c431906692a786e32398d5ef41a1f5900000000000001200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+
+        let tx: LiquidTransaction = serde_json::from_str(json).unwrap();
+        let raw: Transaction = encode::deserialize(&Vec::<u8>::from_hex(raw).unwrap()).unwrap();
+
+        assert_eq!(tx.txid, raw.txid());
+        assert_eq!(tx.locktime, raw.lock_time);
+        assert_eq!(tx.weight, Weight::from_wu(raw.weight() as u64));
+        assert!(tx.vin[0].is_coinbase);
+        assert!(!tx.vin[0].is_pegin);
+        assert_eq!(tx.vin[0].prevout, None);
+        assert_eq!(tx.vin[0].sequence, Sequence::MAX);
+        assert_eq!(tx.vin[0].witness.len(), 1);
+        assert_eq!(tx.vout.len(), 10);
+        assert_eq!(tx.vout[0].script_pubkey, raw.output[0].script_pubkey);
+        assert_eq!(tx.vout[0].asset, Some(AssetId::LIQUID_BTC));
+        assert_eq!(tx.vout[0].value, Some(0));
+        assert_eq!(tx.fee, Amount::ZERO);
+        assert_eq!(
+            tx.status.block_hash,
+            Some(
+                BlockHash::from_str(
+                    "afafbbdfc52a45e51a3b634f391f952f6bdfd14ef74b34925954b4e20d0ad639"
+                )
+                .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_liquid_transaction_output_deserialization() {
+        // Outputs of peg-out transaction 3fe0239f003127e294dd8aad10a91da4998084859159907f90c984823021c1cb
+        let json = r#"[{"scriptpubkey":"76a914216d878ebff0c623909889265d8dc1ab26e2ff4388ac","scriptpubkey_asm":"OP_DUP OP_HASH160 OP_PUSHBYTES_20 216d878ebff0c623909889265d8dc1ab26e2ff43 OP_EQUALVERIFY OP_CHECKSIG","scriptpubkey_type":"p2pkh","scriptpubkey_address":"PzK8dmLjnaFqxqJPCF5HPC6eg4XfT6qNvR","value":1013865,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"},{"scriptpubkey":"6a206fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d61900000000001976a914df662e2dd70fd82acba2d252cc897cb6e618093288ac21025f756509f5dbac47d54c9ef5ccf49895a4dbac4759005a74375f66c480e6c0864da1010ce552be292c37e7242d7e58e678a19349021d22f2712ea68de397b66167d141b09f98e3294e05b51c1469bab3ddb7096f5aa2817e218d137879fb54dbe1659353e6e64add9cb2d6f9e8647bd1ca94d9a6a80d193d76f115596f7bcc8a07eaf85c738f31f4fb192b785aa2934bcb5e4f6a7b444da2bc64da3527a33cc7f0792630f57b92ba07dd0e472d5e2e08b2bca8f1c06e18a07f226dac8acbcc1dfafe8be893d9c5092808b1decfbb955c5f82968bed609b0b2e2c55abe4b0c12bc0c7ea3976e0af2c6aadab3c90ed862a9846fc1a1c20ef220a050538d3c9ff12669653f9b055606dd45fe66f18aa819c8cda5c1b224dc19c0fbf028133d1256588834ea14cb44a84da3af83443657f9ff3eaa14216dc4ed06a92c0ce19be4fe066c9d830ee3acdd3062b9336ace12cc5935953284946bf6bc5c89f9a13d37dddd63e85173174a164f4b68cbc94d347b3d4a7e4ec79044b049375cc7b43b7657123b80f5834afca696b6bc7bf47fa67742e1caa609424cba3ec9d9d156b5909debd0475d91d31134acce50420c2ea694e2c2ea477a0bd14e670bccb42a0fb7009b41ee86a","scriptpubkey_asm":"OP_RETURN OP_PUSHBYTES_32 6fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000 OP_PUSHBYTES_25 76a914df662e2dd70fd82acba2d252cc897cb6e618093288ac OP_PUSHBYTES_33 025f756509f5dbac47d54c9ef5ccf49895a4dbac4759005a74375f66c480e6c086 OP_PUSHDATA2 0ce552be292c37e7242d7e58e678a19349021d22f2712ea68de397b66167d141b09f98e3294e05b51c1469bab3ddb7096f5aa2817e218d137879fb54dbe1659353e6e64add9cb2d6f9e8647bd1ca94d9a6a80d193d76f115596f7bcc8a07eaf85c738f31f4fb192b785aa2934bcb5e4f6a7b444da2bc64da3527a33cc7f0792630f57b92ba07dd0e472d5e2e08b2bca8f1c06e18a07f226dac8acbcc1dfafe8be893d9c5092808b1decfbb955c5f82968bed609b0b2e2c55abe4b0c12bc0c7ea3976e0af2c6aadab3c90ed862a9846fc1a1c20ef220a050538d3c9ff12669653f9b055606dd45fe66f18aa819c8cda5c1b224dc19c0fbf028133d1256588834ea14cb44a84da3af83443657f9ff3eaa14216dc4ed06a92c0ce19be4fe066c9d830ee3acdd3062b9336ace12cc5935953284946bf6bc5c89f9a13d37dddd63e85173174a164f4b68cbc94d347b3d4a7e4ec79044b049375cc7b43b7657123b80f5834afca696b6bc7bf47fa67742e1caa609424cba3ec9d9d156b5909debd0475d91d31134acce50420c2ea694e2c2ea477a0bd14e670bccb42a0fb7009b41ee86a","scriptpubkey_type":"op_return","value":100000,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d","pegout":{"genesis_hash":"000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f","scriptpubkey":"76a914df662e2dd70fd82acba2d252cc897cb6e618093288ac","scriptpubkey_asm":"OP_DUP OP_HASH160 OP_PUSHBYTES_20 df662e2dd70fd82acba2d252cc897cb6e6180932 OP_EQUALVERIFY OP_CHECKSIG","scriptpubkey_address":"1MNE6PxvaJzJeSmbpXCwFfxBJd6h7ZEFTA"}},{"scriptpubkey":"","scriptpubkey_asm":"","scriptpubkey_type":"fee","value":1788,"asset":"6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d"}]"#;
+
+        let vout: Vec<LiquidTransactionOutput> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            vout[0].script_pubkey_address,
+            Some(Address::from_str("PzK8dmLjnaFqxqJPCF5HPC6eg4XfT6qNvR").unwrap())
+        );
+        assert_eq!(vout[0].value, Some(1013865));
+        let pegout = vout[1].pegout.as_ref().unwrap();
+        assert_eq!(
+            pegout.genesis_hash,
+            bitcoin::constants::genesis_block(bitcoin::Network::Bitcoin).block_hash()
+        );
+        assert_eq!(
+            pegout.script_pubkey_address,
+            Some(BitcoinAddress::from_str("1MNE6PxvaJzJeSmbpXCwFfxBJd6h7ZEFTA").unwrap())
+        );
+        assert_eq!(vout[1].value, Some(100000));
+        assert_eq!(vout[2].script_pubkey_type, "fee");
+        assert_eq!(vout[2].value, Some(1788));
+        assert!(!vout[2].is_blinded());
+
+        // Confidential output ae7cd9098c5ee4af9da9e0ed1d119b9cc58a50c803b24de4d0ff068b03bd5151:1
+        let json = r#"{"scriptpubkey":"0014e6d244bb1fc9abfdf5c5c409024ecdcd21809bd3","scriptpubkey_asm":"OP_0 OP_PUSHBYTES_20 e6d244bb1fc9abfdf5c5c409024ecdcd21809bd3","scriptpubkey_type":"v0_p2wpkh","scriptpubkey_address":"ex1qumfyfwclex4lmaw9csysynkde5scpx7n7q94h2","valuecommitment":"0851d16fc993a83f0050954b61d991ab1b6a329fee485b2aff38d62d83ab4470d8","assetcommitment":"0a71ef49e8fcbcc650e63ddd26179c03621491d92e0a8df6bb1244bc34e6f39731"}"#;
+
+        let output: LiquidTransactionOutput = serde_json::from_str(json).unwrap();
+
+        assert!(output.is_blinded());
+        assert_eq!(output.asset, None);
+        assert_eq!(output.value, None);
+        assert!(output.asset_commitment.is_some());
+        assert!(output.value_commitment.is_some());
+    }
+
+    #[test]
+    fn test_liquid_pegs_deserialization() {
+        let json = r#"{"amount":"375899903935","lastBlockUpdate":3101822}"#;
+        let pegs: LiquidPegBalance = serde_json::from_str(json).unwrap();
+        assert_eq!(pegs.amount, Amount::from_sat(375899903935));
+        assert_eq!(pegs.last_block_update, 3101822);
+
+        let json = r#"[{"date":"2018-09-01","amount":"1500000000"},{"date":"2018-10-01","amount":2500000000}]"#;
+        let months: Vec<LiquidMonthlyBalance> = serde_json::from_str(json).unwrap();
+        assert_eq!(months.len(), 2);
+        assert_eq!(months[0].amount, Amount::from_sat(1500000000));
+        assert_eq!(months[1].amount, Amount::from_sat(2500000000));
+    }
+}
@@ -7,10 +7,16 @@
 pub use bitcoin::*;
 pub use url::*;
 
+pub use crate::accelerator::*;
 pub use crate::builder::*;
 pub use crate::client::*;
 pub use crate::error::*;
+#[cfg(feature = "lightning")]
+pub use crate::lightning::*;
+#[cfg(feature = "liquid")]
+pub use crate::liquid::*;
 pub use crate::response::*;
+pub use crate::retry::*;
 #[cfg(feature = "ws")]
 pub use crate::websocket::*;
 pub use crate::*;
@@ -1,15 +1,47 @@
 //! Responses
 
 use std::cmp::Ordering;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
 
+use bitcoin::absolute::LockTime;
 use bitcoin::address::{Address, NetworkUnchecked};
-use bitcoin::{Amount, BlockHash, FeeRate, TxMerkleNode, Weight};
-use serde::{Deserialize, Serialize};
+use bitcoin::{
+    Amount, BlockHash, FeeRate, OutPoint, ScriptBuf, Sequence, TxMerkleNode, Txid, Weight, Witness,
+    Wtxid,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::deser;
 use crate::error::Error;
 
+/// Implement equality and ordering by `timestamp`, so that entries can be collected in a [`BTreeSet`].
+macro_rules! impl_timestamp_ord {
+    ($name:ident) => {
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.timestamp == other.timestamp
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.timestamp.cmp(&other.timestamp)
+            }
+        }
+    };
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct ErrorResponse {
     pub(crate) error: String,
@@ -31,33 +63,189 @@ impl<T> MempoolResponse<T> {
     }
 }
 
-/// Prices
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+/// Fiat currency
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Currency {
+    /// US Dollar
+    Usd,
+    /// Euro
+    Eur,
+    /// British Pound
+    Gbp,
+    /// Canadian Dollar
+    Cad,
+    /// Swiss Franc
+    Chf,
+    /// Australian Dollar
+    Aud,
+    /// Japanese Yen
+    Jpy,
+    /// Any other currency, by code
+    Other(String),
+}
+
+impl Currency {
+    /// Get the currency code
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Usd => "USD",
+            Self::Eur => "EUR",
+            Self::Gbp => "GBP",
+            Self::Cad => "CAD",
+            Self::Chf => "CHF",
+            Self::Aud => "AUD",
+            Self::Jpy => "JPY",
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "USD" => Self::Usd,
+            "EUR" => Self::Eur,
+            "GBP" => Self::Gbp,
+            "CAD" => Self::Cad,
+            "CHF" => Self::Chf,
+            "AUD" => Self::Aud,
+            "JPY" => Self::Jpy,
+            code => Self::Other(code.to_string()),
+        })
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code: String = String::deserialize(deserializer)?;
+        let Ok(currency) = Self::from_str(&code);
+        Ok(currency)
+    }
+}
+
+/// Bitcoin prices
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Prices {
     /// Timestamp
     #[serde(rename = "time")]
     pub timestamp: u64,
-    /// USD
-    #[serde(rename = "USD")]
-    pub usd: u32,
-    /// EUR
-    #[serde(rename = "EUR")]
-    pub eur: u32,
-    /// GBP
-    #[serde(rename = "GBP")]
-    pub gbp: u32,
-    /// CAD
-    #[serde(rename = "CAD")]
-    pub cad: u32,
-    /// CHF
-    #[serde(rename = "CHF")]
-    pub chf: u32,
-    /// AUD
-    #[serde(rename = "AUD")]
-    pub aud: u32,
-    /// JPY
-    #[serde(rename = "JPY")]
-    pub jpy: u32,
+    /// Price of 1 BTC, keyed by currency
+    #[serde(flatten)]
+    pub rates: BTreeMap<Currency, f64>,
+}
+
+impl Prices {
+    /// Get the price of 1 BTC in a currency
+    #[inline]
+    pub fn get(&self, currency: &Currency) -> Option<f64> {
+        self.rates.get(currency).copied()
+    }
+
+    /// Get the price of 1 BTC in USD
+    #[inline]
+    pub fn usd(&self) -> Option<f64> {
+        self.get(&Currency::Usd)
+    }
+
+    /// Get the price of 1 BTC in EUR
+    #[inline]
+    pub fn eur(&self) -> Option<f64> {
+        self.get(&Currency::Eur)
+    }
+
+    /// Get the price of 1 BTC in GBP
+    #[inline]
+    pub fn gbp(&self) -> Option<f64> {
+        self.get(&Currency::Gbp)
+    }
+
+    /// Get the price of 1 BTC in CAD
+    #[inline]
+    pub fn cad(&self) -> Option<f64> {
+        self.get(&Currency::Cad)
+    }
+
+    /// Get the price of 1 BTC in CHF
+    #[inline]
+    pub fn chf(&self) -> Option<f64> {
+        self.get(&Currency::Chf)
+    }
+
+    /// Get the price of 1 BTC in AUD
+    #[inline]
+    pub fn aud(&self) -> Option<f64> {
+        self.get(&Currency::Aud)
+    }
+
+    /// Get the price of 1 BTC in JPY
+    #[inline]
+    pub fn jpy(&self) -> Option<f64> {
+        self.get(&Currency::Jpy)
+    }
+
+    /// Price of 1 BTC in a currency, if positive and finite
+    fn valid_price(&self, currency: &Currency) -> Option<f64> {
+        let price: f64 = self.get(currency)?;
+        (price.is_normal() && price > 0.0).then_some(price)
+    }
+
+    /// Convert a bitcoin amount to fiat.
+    ///
+    /// Returns `None` if there is no valid price for the currency.
+    pub fn to_fiat(&self, amount: Amount, currency: &Currency) -> Option<f64> {
+        let price: f64 = self.valid_price(currency)?;
+        Some(amount.to_btc() * price)
+    }
+
+    /// Convert a fiat value to a bitcoin amount, rounded to the nearest satoshi.
+    ///
+    /// Returns `None` if there is no valid price for the currency, if `fiat` is negative
+    /// or if the amount exceeds the 21 million BTC supply.
+    pub fn to_amount(&self, fiat: f64, currency: &Currency) -> Option<Amount> {
+        let price: f64 = self.valid_price(currency)?;
+
+        if !fiat.is_finite() || fiat < 0.0 {
+            return None;
+        }
+
+        let sat: f64 = (fiat / price * 100_000_000.0).round();
+
+        if sat > Amount::MAX_MONEY.to_sat() as f64 {
+            return None;
+        }
+
+        Some(Amount::from_sat(sat as u64))
+    }
+}
+
+/// Historical prices
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct HistoricalPrices {
+    /// Prices
+    pub prices: Vec<Prices>,
+    /// Exchange rates from USD, keyed by currency pair (e.g., `USDEUR`)
+    #[serde(rename = "exchangeRates")]
+    pub exchange_rates: BTreeMap<String, f64>,
 }
 
 /// Bitcoin difficulty adjustment information
@@ -106,7 +294,18 @@ pub struct AddressStats {
     pub mempool_stats: TransactionStats,
 }
 
-/// Transaction statistics for an address
+/// Script hash statistics
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ScriptHashStats {
+    /// Electrum-style script hash (reversed SHA256 of the script, hex-encoded)
+    pub scripthash: String,
+    /// On-chain statistics
+    pub chain_stats: TransactionStats,
+    /// Mempool statistics
+    pub mempool_stats: TransactionStats,
+}
+
+/// Transaction statistics for an address or a script hash
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct TransactionStats {
     /// Number of funded transaction outputs
@@ -121,6 +320,400 @@ pub struct TransactionStats {
     pub tx_count: u32,
 }
 
+/// Transaction confirmation status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TransactionStatus {
+    /// Whether the transaction is confirmed
+    pub confirmed: bool,
+    /// Height of the block that includes the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_height: Option<u32>,
+    /// Hash of the block that includes the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<BlockHash>,
+    /// UNIX timestamp of the block that includes the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_time: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct UtxoRaw {
+    txid: Txid,
+    vout: u32,
+    status: TransactionStatus,
+    value: Amount,
+}
+
+impl From<UtxoRaw> for Utxo {
+    fn from(raw: UtxoRaw) -> Self {
+        Self {
+            outpoint: OutPoint::new(raw.txid, raw.vout),
+            value: raw.value,
+            status: raw.status,
+        }
+    }
+}
+
+impl From<Utxo> for UtxoRaw {
+    fn from(utxo: Utxo) -> Self {
+        Self {
+            txid: utxo.outpoint.txid,
+            vout: utxo.outpoint.vout,
+            status: utxo.status,
+            value: utxo.value,
+        }
+    }
+}
+
+/// Unspent transaction output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(from = "UtxoRaw", into = "UtxoRaw")]
+pub struct Utxo {
+    /// Outpoint
+    pub outpoint: OutPoint,
+    /// Output value
+    pub value: Amount,
+    /// Confirmation status
+    pub status: TransactionStatus,
+}
+
+/// Transaction output
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TransactionOutput {
+    /// Script pubkey
+    #[serde(rename = "scriptpubkey")]
+    pub script_pubkey: ScriptBuf,
+    /// Script pubkey ASM representation
+    #[serde(rename = "scriptpubkey_asm")]
+    pub script_pubkey_asm: String,
+    /// Script pubkey type (e.g., `v0_p2wpkh`, `p2pkh`, `op_return`)
+    #[serde(rename = "scriptpubkey_type")]
+    pub script_pubkey_type: String,
+    /// Script pubkey address (if any)
+    #[serde(rename = "scriptpubkey_address")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script_pubkey_address: Option<Address<NetworkUnchecked>>,
+    /// Output value
+    pub value: Amount,
+}
+
+/// Transaction input
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TransactionInput {
+    /// ID of the transaction being spent
+    pub txid: Txid,
+    /// Index of the output being spent
+    pub vout: u32,
+    /// Output being spent (missing for coinbase inputs)
+    pub prevout: Option<TransactionOutput>,
+    /// Script signature
+    #[serde(rename = "scriptsig")]
+    pub script_sig: ScriptBuf,
+    /// Script signature ASM representation
+    #[serde(rename = "scriptsig_asm")]
+    pub script_sig_asm: String,
+    /// Witness data
+    #[serde(default)]
+    pub witness: Witness,
+    /// Whether this is a coinbase input
+    pub is_coinbase: bool,
+    /// Sequence number
+    pub sequence: Sequence,
+    /// Inner redeem script ASM representation (P2SH inputs only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner_redeemscript_asm: Option<String>,
+    /// Inner witness script ASM representation (P2WSH inputs only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner_witnessscript_asm: Option<String>,
+}
+
+/// Transaction information
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TransactionInfo {
+    /// Transaction ID
+    pub txid: Txid,
+    /// Transaction version
+    pub version: i32,
+    /// Lock time
+    pub locktime: LockTime,
+    /// Inputs
+    pub vin: Vec<TransactionInput>,
+    /// Outputs
+    pub vout: Vec<TransactionOutput>,
+    /// Transaction size in bytes
+    pub size: u32,
+    /// Transaction weight
+    pub weight: Weight,
+    /// Signature operations count
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sigops: Option<u32>,
+    /// Transaction fee
+    pub fee: Amount,
+    /// Confirmation status
+    pub status: TransactionStatus,
+}
+
+impl TransactionInfo {
+    /// Virtual size of the transaction
+    #[inline]
+    pub fn vsize(&self) -> u64 {
+        self.weight.to_vbytes_ceil()
+    }
+
+    /// Fee rate paid by the transaction
+    pub fn fee_rate(&self) -> FeeRate {
+        self.fee / self.weight
+    }
+}
+
+/// Merkle inclusion proof of a transaction
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct MerkleProof {
+    /// Height of the block that includes the transaction
+    pub block_height: u32,
+    /// Merkle branch, from the leaf to the root
+    pub merkle: Vec<TxMerkleNode>,
+    /// Position of the transaction in the block
+    pub pos: u32,
+}
+
+/// Fees of a transaction evaluated for mempool acceptance
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct MempoolAcceptFees {
+    /// Transaction fee
+    #[serde(with = "bitcoin::amount::serde::as_btc")]
+    pub base: Amount,
+    /// Effective fee rate of the package the transaction was evaluated with
+    #[serde(rename = "effective-feerate")]
+    #[serde(default, with = "deser::optional_fee_rate_btc_kvb_serde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_fee_rate: Option<FeeRate>,
+    /// Transactions whose fees and vsizes are included in the effective fee rate
+    #[serde(rename = "effective-includes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_includes: Option<Vec<Wtxid>>,
+}
+
+/// Result of a mempool acceptance test
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct MempoolAcceptResult {
+    /// Transaction ID
+    pub txid: Txid,
+    /// Witness transaction ID
+    pub wtxid: Wtxid,
+    /// Whether the transaction would be accepted to the mempool
+    ///
+    /// Missing if the package validation failed before reaching this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed: Option<bool>,
+    /// Virtual size of the transaction (only if allowed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vsize: Option<u64>,
+    /// Transaction fees (only if allowed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fees: Option<MempoolAcceptFees>,
+    /// Rejection reason (only if not allowed)
+    #[serde(rename = "reject-reason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+    /// Package validation error (if any)
+    #[serde(rename = "package-error")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_error: Option<String>,
+}
+
+/// Result of a transaction submitted within a package
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PackageTransactionResult {
+    /// Transaction ID
+    pub txid: Txid,
+    /// Witness transaction ID of the transaction already in the mempool with the same txid (if any)
+    #[serde(rename = "other-wtxid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other_wtxid: Option<Wtxid>,
+    /// Virtual size of the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vsize: Option<u64>,
+    /// Transaction fees
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fees: Option<MempoolAcceptFees>,
+    /// Transaction error (if any)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of a package submission
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PackageSubmission {
+    /// Package result message (`success` if the package was accepted)
+    pub package_msg: String,
+    /// Transaction results, keyed by witness transaction ID
+    #[serde(rename = "tx-results")]
+    pub tx_results: BTreeMap<Wtxid, PackageTransactionResult>,
+    /// Transactions replaced by the package
+    #[serde(rename = "replaced-transactions")]
+    #[serde(default)]
+    pub replaced_transactions: Vec<Txid>,
+}
+
+impl PackageSubmission {
+    /// Check if the package was accepted
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        self.package_msg == "success"
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub(crate) struct OutspendResponse {
+    spent: bool,
+    txid: Option<Txid>,
+    vin: Option<u32>,
+    status: Option<TransactionStatus>,
+}
+
+impl OutspendResponse {
+    /// Convert into an [`Outspend`], `None` if the output is unspent.
+    ///
+    /// A spent output without its spending details is an error, never `None`.
+    pub(crate) fn into_outspend(self) -> Result<Option<Outspend>, Error> {
+        if !self.spent {
+            return Ok(None);
+        }
+
+        match (self.txid, self.vin, self.status) {
+            (Some(txid), Some(vin), Some(status)) => Ok(Some(Outspend { txid, vin, status })),
+            _ => Err(Error::Mempool(String::from(
+                "Spent output without spending details",
+            ))),
+        }
+    }
+}
+
+/// Spending details of a transaction output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Outspend {
+    /// ID of the spending transaction
+    pub txid: Txid,
+    /// Index of the spending input
+    pub vin: u32,
+    /// Confirmation status of the spending transaction
+    pub status: TransactionStatus,
+}
+
+/// Transaction in a CPFP cluster
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct CpfpTransaction {
+    /// Transaction ID
+    pub txid: Txid,
+    /// Transaction fee
+    pub fee: Amount,
+    /// Transaction weight
+    pub weight: Weight,
+}
+
+/// CPFP (Child Pays For Parent) information of a transaction
+///
+/// Confirmed and unknown transactions only have (empty) `ancestors`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct CpfpInfo {
+    /// Unconfirmed ancestors
+    #[serde(default, deserialize_with = "deser::null_as_default")]
+    pub ancestors: Vec<CpfpTransaction>,
+    /// Unconfirmed descendants
+    #[serde(default, deserialize_with = "deser::null_as_default")]
+    pub descendants: Vec<CpfpTransaction>,
+    /// Descendant with the highest fee rate (if any)
+    #[serde(rename = "bestDescendant")]
+    #[serde(default)]
+    pub best_descendant: Option<CpfpTransaction>,
+    /// Effective fee rate of the package (unconfirmed transactions only)
+    #[serde(rename = "effectiveFeePerVsize")]
+    #[serde(default, with = "deser::optional_fee_rate_f64_serde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_fee_rate: Option<FeeRate>,
+    /// Signature operations count
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sigops: Option<u32>,
+    /// Virtual size, adjusted for signature operations
+    #[serde(rename = "adjustedVsize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjusted_vsize: Option<f64>,
+}
+
+/// Transaction in a RBF (Replace By Fee) replacement tree
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct RbfTransaction {
+    /// Transaction ID
+    pub txid: Txid,
+    /// Transaction fee
+    pub fee: Amount,
+    /// Virtual size
+    pub vsize: f64,
+    /// Total output value
+    pub value: Amount,
+    /// Fee rate
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub rate: FeeRate,
+    /// UNIX timestamp of when the transaction was first seen
+    pub time: u64,
+    /// Whether the transaction signals RBF
+    pub rbf: bool,
+    /// Whether the transaction was replaced without signaling RBF
+    #[serde(rename = "fullRbf")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_rbf: Option<bool>,
+    /// Whether the transaction was mined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mined: Option<bool>,
+}
+
+/// RBF (Replace By Fee) replacement tree
+///
+/// The root is the latest replacement; `replaces` contains the transactions it replaced.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct RbfTree {
+    /// Replacement transaction
+    pub tx: RbfTransaction,
+    /// UNIX timestamp of the replacement
+    pub time: u64,
+    /// Whether any replacement in the tree was a full RBF (without signaling)
+    #[serde(rename = "fullRbf")]
+    pub full_rbf: bool,
+    /// Whether the replacement was mined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mined: Option<bool>,
+    /// Seconds elapsed between the replaced transaction and this replacement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<u64>,
+    /// Replaced transactions
+    pub replaces: Vec<RbfTree>,
+}
+
+impl RbfTree {
+    /// Get the IDs of all the transactions replaced, directly or indirectly, by the root
+    pub fn replaced_txids(&self) -> Vec<Txid> {
+        let mut txids: Vec<Txid> = Vec::new();
+        let mut stack: Vec<&RbfTree> = self.replaces.iter().collect();
+
+        while let Some(node) = stack.pop() {
+            txids.push(node.tx.txid);
+            stack.extend(node.replaces.iter());
+        }
+
+        txids
+    }
+}
+
+/// RBF (Replace By Fee) history of a transaction
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct RbfHistory {
+    /// Replacement tree the transaction belongs to (if any)
+    pub replacements: Option<RbfTree>,
+    /// Transactions directly replaced by the transaction (if any)
+    pub replaces: Option<Vec<Txid>>,
+}
+
 /// Block Info
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct BlockInfo {
@@ -155,6 +748,19 @@ pub struct BlockInfo {
     pub difficulty: f64,
 }
 
+/// Block status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BlockStatus {
+    /// Whether the block is part of the best chain
+    pub in_best_chain: bool,
+    /// Block height (only if in the best chain)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Hash of the next block in the best chain (only if in the best chain and not the tip)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_best: Option<BlockHash>,
+}
+
 /// Mining pool information
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct MiningPool {
@@ -166,7 +772,111 @@ pub struct MiningPool {
     pub slug: String,
     /// Miner names (if available)
     #[serde(rename = "minerNames")]
+    #[serde(default)]
     pub miner_names: Option<Vec<String>>,
+    /// Pool website
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    /// Known coinbase payout addresses
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addresses: Option<Vec<String>>,
+    /// Regexes matching the coinbase tag
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regexes: Option<Vec<String>>,
+    /// Unique pool ID
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_id: Option<u32>,
+}
+
+/// Mining pool statistics over a period
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct MiningPoolStats {
+    /// Pool ID
+    #[serde(rename = "poolId")]
+    pub pool_id: u32,
+    /// Pool name
+    pub name: String,
+    /// Pool website
+    pub link: String,
+    /// Pool slug
+    pub slug: String,
+    /// Rank by number of blocks mined
+    pub rank: u32,
+    /// Number of blocks mined
+    #[serde(rename = "blockCount")]
+    pub block_count: u32,
+    /// Number of empty blocks mined
+    #[serde(rename = "emptyBlocks")]
+    pub empty_blocks: u32,
+    /// Average block template match rate
+    #[serde(rename = "avgMatchRate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_match_rate: Option<f64>,
+    /// Average fee delta compared to the block templates
+    #[serde(rename = "avgFeeDelta")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_fee_delta: Option<String>,
+    /// Unique pool ID
+    #[serde(rename = "poolUniqueId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_unique_id: Option<u32>,
+}
+
+/// Mining pools ranking over a period
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct MiningPoolsRanking {
+    /// Pools, sorted by rank
+    pub pools: Vec<MiningPoolStats>,
+    /// Total number of blocks mined in the period
+    #[serde(rename = "blockCount")]
+    pub block_count: u32,
+    /// Last estimated network hashrate
+    #[serde(rename = "lastEstimatedHashrate")]
+    pub last_estimated_hashrate: f64,
+}
+
+/// Mining pool values over the usual time windows
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct MiningPoolWindows<T> {
+    /// All time
+    pub all: T,
+    /// Last 24 hours
+    #[serde(rename = "24h")]
+    pub last_24h: T,
+    /// Last week
+    #[serde(rename = "1w")]
+    pub last_week: T,
+}
+
+/// Mining pool details
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct MiningPoolDetails {
+    /// Pool information
+    pub pool: MiningPool,
+    /// Number of blocks mined
+    #[serde(rename = "blockCount")]
+    pub block_count: MiningPoolWindows<u32>,
+    /// Share of the blocks mined
+    #[serde(rename = "blockShare")]
+    pub block_share: MiningPoolWindows<f64>,
+    /// Estimated hashrate
+    #[serde(rename = "estimatedHashrate")]
+    pub estimated_hashrate: f64,
+    /// Hashrate reported by the pool (if any)
+    #[serde(rename = "reportedHashrate")]
+    pub reported_hashrate: Option<f64>,
+    /// Total reward earned
+    #[serde(rename = "totalReward")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_reward: Option<Amount>,
+    /// Average block health
+    #[serde(rename = "avgBlockHealth")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_block_health: Option<f64>,
 }
 
 /// Block extras information containing detailed statistics
@@ -325,6 +1035,93 @@ pub struct BlockInfoV1 {
     pub extras: BlockExtras,
 }
 
+/// Stripped transaction, as included in block summaries and templates
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct StrippedTransaction {
+    /// Transaction ID
+    pub txid: Txid,
+    /// Transaction fee
+    pub fee: Amount,
+    /// Virtual size
+    pub vsize: f64,
+    /// Total output value
+    pub value: Amount,
+    /// Fee rate
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub rate: FeeRate,
+    /// Transaction flags bitmask
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+    /// UNIX timestamp of when the transaction was first seen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<u64>,
+}
+
+/// Block audit summary, comparing a mined block with the expected block template
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct BlockAuditSummary {
+    /// Block height
+    pub height: u32,
+    /// Block hash
+    pub id: BlockHash,
+    /// UNIX timestamp
+    pub timestamp: u64,
+    /// Expected block template
+    pub template: Vec<StrippedTransaction>,
+    /// Transactions expected in the template but missing from the block
+    #[serde(rename = "missingTxs")]
+    pub missing_txs: Vec<Txid>,
+    /// Transactions in the block but not expected in the template
+    #[serde(rename = "addedTxs")]
+    pub added_txs: Vec<Txid>,
+    /// Transactions prioritized by the miner
+    #[serde(rename = "prioritizedTxs")]
+    #[serde(default)]
+    pub prioritized_txs: Vec<Txid>,
+    /// Transactions too recent to be expected in the template
+    #[serde(rename = "freshTxs")]
+    #[serde(default)]
+    pub fresh_txs: Vec<Txid>,
+    /// Transactions with a high number of signature operations
+    #[serde(rename = "sigopTxs")]
+    #[serde(default)]
+    pub sigop_txs: Vec<Txid>,
+    /// Full RBF replacements (of transactions not signaling RBF)
+    #[serde(rename = "fullrbfTxs")]
+    #[serde(default)]
+    pub fullrbf_txs: Vec<Txid>,
+    /// Accelerated transactions
+    #[serde(rename = "acceleratedTxs")]
+    #[serde(default)]
+    pub accelerated_txs: Vec<Txid>,
+    /// Block template match rate
+    #[serde(rename = "matchRate")]
+    pub match_rate: f64,
+    /// Expected fees
+    #[serde(rename = "expectedFees")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_fees: Option<Amount>,
+    /// Expected weight
+    #[serde(rename = "expectedWeight")]
+    #[serde(default, with = "deser::optional_weight_serde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_weight: Option<Weight>,
+}
+
+/// Block audit score entry
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BlockAuditEntry {
+    /// UNIX timestamp
+    #[serde(rename = "time")]
+    pub timestamp: u64,
+    /// Block height
+    pub height: u32,
+    /// Block template match rate of the block, in percent
+    pub match_rate: f64,
+}
+
+impl_timestamp_ord!(BlockAuditEntry);
+
 /// Hashrate stats entry
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct HashrateEntry {
@@ -336,25 +1133,24 @@ pub struct HashrateEntry {
     pub avg_hashrate: f64,
 }
 
-impl PartialEq for HashrateEntry {
-    fn eq(&self, other: &Self) -> bool {
-        self.timestamp == other.timestamp
-    }
-}
-
-impl Eq for HashrateEntry {}
+impl_timestamp_ord!(HashrateEntry);
 
-impl PartialOrd for HashrateEntry {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+/// Mining pool hashrate entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolHashrateEntry {
+    /// UNIX timestamp
+    pub timestamp: u64,
+    /// Average hashrate
+    #[serde(rename = "avgHashrate")]
+    pub avg_hashrate: f64,
+    /// Share of the network hashrate
+    pub share: f64,
+    /// Pool name
+    #[serde(rename = "poolName")]
+    pub pool_name: String,
 }
 
-impl Ord for HashrateEntry {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.timestamp.cmp(&other.timestamp)
-    }
-}
+impl_timestamp_ord!(PoolHashrateEntry);
 
 /// Difficulty entry
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -371,23 +1167,23 @@ pub struct DifficultyEntry {
     pub adjustment: Option<f32>,
 }
 
-impl PartialEq for DifficultyEntry {
-    fn eq(&self, other: &Self) -> bool {
-        self.timestamp == other.timestamp
-    }
-}
-
-impl Eq for DifficultyEntry {}
+impl_timestamp_ord!(DifficultyEntry);
 
-impl PartialOrd for DifficultyEntry {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
+/// Past difficulty adjustment, as returned by `/api/v1/mining/difficulty-adjustments`:
+/// `[timestamp, height, difficulty, adjustment ratio]`
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub(crate) struct DifficultyAdjustmentResponse(u64, u32, f64, f32);
 
-impl Ord for DifficultyEntry {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.timestamp.cmp(&other.timestamp)
+impl From<DifficultyAdjustmentResponse> for DifficultyEntry {
+    fn from(
+        DifficultyAdjustmentResponse(timestamp, height, difficulty, adjustment): DifficultyAdjustmentResponse,
+    ) -> Self {
+        Self {
+            timestamp,
+            height,
+            difficulty,
+            adjustment: Some(adjustment),
+        }
     }
 }
 
@@ -406,6 +1202,132 @@ pub struct HashrateStats {
     pub current_difficulty: f64,
 }
 
+/// Average block fees entry
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BlockFeesEntry {
+    /// Average block height
+    #[serde(rename = "avgHeight")]
+    pub avg_height: u32,
+    /// UNIX timestamp
+    pub timestamp: u64,
+    /// Average total fees per block
+    #[serde(rename = "avgFees")]
+    pub avg_fees: Amount,
+    /// Average total fees per block, in USD
+    #[serde(rename = "USD")]
+    pub usd: f64,
+}
+
+impl_timestamp_ord!(BlockFeesEntry);
+
+/// Average block rewards entry
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BlockRewardsEntry {
+    /// Average block height
+    #[serde(rename = "avgHeight")]
+    pub avg_height: u32,
+    /// UNIX timestamp
+    pub timestamp: u64,
+    /// Average reward (subsidy + fees) per block
+    #[serde(rename = "avgRewards")]
+    pub avg_rewards: Amount,
+    /// Average reward per block, in USD
+    #[serde(rename = "USD")]
+    pub usd: f64,
+}
+
+impl_timestamp_ord!(BlockRewardsEntry);
+
+/// Average block fee rate percentiles entry
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BlockFeeRatesEntry {
+    /// Average block height
+    #[serde(rename = "avgHeight")]
+    pub avg_height: u32,
+    /// UNIX timestamp
+    pub timestamp: u64,
+    /// Minimum fee rate
+    #[serde(rename = "avgFee_0")]
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub avg_fee_0: FeeRate,
+    /// 10th percentile fee rate
+    #[serde(rename = "avgFee_10")]
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub avg_fee_10: FeeRate,
+    /// 25th percentile fee rate
+    #[serde(rename = "avgFee_25")]
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub avg_fee_25: FeeRate,
+    /// Median fee rate
+    #[serde(rename = "avgFee_50")]
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub avg_fee_50: FeeRate,
+    /// 75th percentile fee rate
+    #[serde(rename = "avgFee_75")]
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub avg_fee_75: FeeRate,
+    /// 90th percentile fee rate
+    #[serde(rename = "avgFee_90")]
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub avg_fee_90: FeeRate,
+    /// Maximum fee rate
+    #[serde(rename = "avgFee_100")]
+    #[serde(with = "deser::fee_rate_f64_serde")]
+    pub avg_fee_100: FeeRate,
+}
+
+impl_timestamp_ord!(BlockFeeRatesEntry);
+
+/// Average block size entry
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BlockSizeEntry {
+    /// Average block height
+    #[serde(rename = "avgHeight")]
+    pub avg_height: u32,
+    /// UNIX timestamp
+    pub timestamp: u64,
+    /// Average block size in bytes
+    #[serde(rename = "avgSize")]
+    pub avg_size: u64,
+}
+
+impl_timestamp_ord!(BlockSizeEntry);
+
+/// Average block weight entry
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BlockWeightEntry {
+    /// Average block height
+    #[serde(rename = "avgHeight")]
+    pub avg_height: u32,
+    /// UNIX timestamp
+    pub timestamp: u64,
+    /// Average block weight
+    #[serde(rename = "avgWeight")]
+    pub avg_weight: Weight,
+}
+
+impl_timestamp_ord!(BlockWeightEntry);
+
+/// Average block sizes and weights
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BlockSizesAndWeights {
+    /// Sizes
+    pub sizes: BTreeSet<BlockSizeEntry>,
+    /// Weights
+    pub weights: BTreeSet<BlockWeightEntry>,
+}
+
+/// Block mined at a given time
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BlockTimestamp {
+    /// Block height
+    pub height: u32,
+    /// Block hash
+    pub hash: BlockHash,
+    /// Block time, as an ISO 8601 string
+    pub timestamp: String,
+}
+
 /// Bitcoin fee recommendations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct FeeRecommendations {
@@ -706,6 +1628,10 @@ mod tests {
                     name: "Unknown".to_string(),
                     slug: "unknown".to_string(),
                     miner_names: None,
+                    link: None,
+                    addresses: None,
+                    regexes: None,
+                    unique_id: None,
                 },
                 avg_fee: Amount::from_sat(12068),
                 avg_fee_rate: FeeRate::from_sat_per_vb_unchecked(20),
@@ -745,6 +1671,678 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_transaction_info_deserialization() {
+        // The first bitcoin transaction, from Satoshi Nakamoto to Hal Finney
+        let json = r#"{
+  "txid": "f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16",
+  "version": 1,
+  "locktime": 0,
+  "vin": [
+    {
+      "txid": "0437cd7f8525ceed2324359c2d0ba26006d92d856a9c20fa0241106ee5a597c9",
+      "vout": 0,
+      "prevout": {
+        "scriptpubkey": "410411db93e1dcdb8a016b49840f8c53bc1eb68a382e97b1482ecad7b148a6909a5cb2e0eaddfb84ccf9744464f82e160bfa9b8b64f9d4c03f999b8643f656b412a3ac",
+        "scriptpubkey_asm": "OP_PUSHBYTES_65 0411db93e1dcdb8a016b49840f8c53bc1eb68a382e97b1482ecad7b148a6909a5cb2e0eaddfb84ccf9744464f82e160bfa9b8b64f9d4c03f999b8643f656b412a3 OP_CHECKSIG",
+        "scriptpubkey_type": "p2pk",
+        "value": 5000000000
+      },
+      "scriptsig": "47304402204e45e16932b8af514961a1d3a1a25fdf3f4f7732e9d624c6c61548ab5fb8cd410220181522ec8eca07de4860a4acdd12909d831cc56cbbac4622082221a8768d1d0901",
+      "scriptsig_asm": "OP_PUSHBYTES_71 304402204e45e16932b8af514961a1d3a1a25fdf3f4f7732e9d624c6c61548ab5fb8cd410220181522ec8eca07de4860a4acdd12909d831cc56cbbac4622082221a8768d1d0901",
+      "is_coinbase": false,
+      "sequence": 4294967295
+    }
+  ],
+  "vout": [
+    {
+      "scriptpubkey": "4104ae1a62fe09c5f51b13905f07f06b99a2f7159b2225f374cd378d71302fa28414e7aab37397f554a7df5f142c21c1b7303b8a0626f1baded5c72a704f7e6cd84cac",
+      "scriptpubkey_asm": "OP_PUSHBYTES_65 04ae1a62fe09c5f51b13905f07f06b99a2f7159b2225f374cd378d71302fa28414e7aab37397f554a7df5f142c21c1b7303b8a0626f1baded5c72a704f7e6cd84c OP_CHECKSIG",
+      "scriptpubkey_type": "p2pk",
+      "value": 1000000000
+    },
+    {
+      "scriptpubkey": "410411db93e1dcdb8a016b49840f8c53bc1eb68a382e97b1482ecad7b148a6909a5cb2e0eaddfb84ccf9744464f82e160bfa9b8b64f9d4c03f999b8643f656b412a3ac",
+      "scriptpubkey_asm": "OP_PUSHBYTES_65 0411db93e1dcdb8a016b49840f8c53bc1eb68a382e97b1482ecad7b148a6909a5cb2e0eaddfb84ccf9744464f82e160bfa9b8b64f9d4c03f999b8643f656b412a3 OP_CHECKSIG",
+      "scriptpubkey_type": "p2pk",
+      "value": 4000000000
+    }
+  ],
+  "size": 275,
+  "weight": 1100,
+  "sigops": 8,
+  "fee": 0,
+  "status": {
+    "confirmed": true,
+    "block_height": 170,
+    "block_hash": "00000000d1145790a8694403d4063f323d499e655c83426834d4ce2f8dd4a2ee",
+    "block_time": 1231731025
+  }
+}"#;
+
+        let tx: TransactionInfo = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            tx.txid,
+            Txid::from_str("f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16")
+                .unwrap()
+        );
+        assert_eq!(tx.version, 1);
+        assert_eq!(tx.locktime, LockTime::ZERO);
+        assert_eq!(tx.weight, Weight::from_wu(1100));
+        assert_eq!(tx.vsize(), 275);
+        assert_eq!(tx.fee, Amount::ZERO);
+        assert_eq!(tx.sigops, Some(8));
+
+        // Inputs
+        assert_eq!(tx.vin.len(), 1);
+        let input = &tx.vin[0];
+        assert_eq!(input.vout, 0);
+        assert!(!input.is_coinbase);
+        assert_eq!(input.script_sig.len(), 72);
+        assert!(input.witness.is_empty());
+        assert_eq!(input.sequence, Sequence::MAX);
+        let prevout = input.prevout.as_ref().unwrap();
+        assert!(prevout.script_pubkey.is_p2pk());
+        assert_eq!(prevout.script_pubkey_address, None);
+        assert_eq!(prevout.value, Amount::from_int_btc(50));
+
+        // Outputs
+        assert_eq!(tx.vout.len(), 2);
+        assert!(tx.vout[0].script_pubkey.is_p2pk());
+        assert_eq!(tx.vout[0].script_pubkey_type, "p2pk");
+        assert_eq!(tx.vout[0].value, Amount::from_int_btc(10));
+        assert_eq!(tx.vout[1].script_pubkey, prevout.script_pubkey);
+        assert_eq!(tx.vout[1].value, Amount::from_int_btc(40));
+
+        // Status
+        assert_eq!(
+            tx.status,
+            TransactionStatus {
+                confirmed: true,
+                block_height: Some(170),
+                block_hash: Some(
+                    BlockHash::from_str(
+                        "00000000d1145790a8694403d4063f323d499e655c83426834d4ce2f8dd4a2ee"
+                    )
+                    .unwrap()
+                ),
+                block_time: Some(1231731025),
+            }
+        );
+    }
+
+    #[test]
+    fn test_utxo_deserialization() {
+        // Genesis coinbase output, listed for 1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa
+        let json = r#"[{"txid":"4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b","vout":0,"status":{"confirmed":true,"block_height":0,"block_hash":"000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f","block_time":1231006505},"value":5000000000}]"#;
+
+        let utxos: Vec<Utxo> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            utxos,
+            vec![Utxo {
+                outpoint: OutPoint::from_str(
+                    "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0"
+                )
+                .unwrap(),
+                value: Amount::from_int_btc(50),
+                status: TransactionStatus {
+                    confirmed: true,
+                    block_height: Some(0),
+                    block_hash: Some(
+                        BlockHash::from_str(
+                            "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+                        )
+                        .unwrap()
+                    ),
+                    block_time: Some(1231006505),
+                },
+            }]
+        );
+
+        // Serialize
+        let serialized = serde_json::to_string(&utxos).unwrap();
+        assert_eq!(serialized, json);
+    }
+
+    #[test]
+    fn test_merkle_proof_deserialization() {
+        let json = r#"{"block_height":363348,"merkle":["acf931fe8980c6165b32fe7a8d25f779af7870a638599db1977d5309e24d2478","ee25997c2520236892c6a67402650e6b721899869dcf6715294e98c0b45623f9"],"pos":1465}"#;
+
+        let proof: MerkleProof = serde_json::from_str(json).unwrap();
+
+        assert_eq!(proof.block_height, 363348);
+        assert_eq!(proof.pos, 1465);
+        assert_eq!(
+            proof.merkle,
+            vec![
+                TxMerkleNode::from_str(
+                    "acf931fe8980c6165b32fe7a8d25f779af7870a638599db1977d5309e24d2478"
+                )
+                .unwrap(),
+                TxMerkleNode::from_str(
+                    "ee25997c2520236892c6a67402650e6b721899869dcf6715294e98c0b45623f9"
+                )
+                .unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mempool_accept_result_deserialization() {
+        let json = r#"[
+  {
+    "txid": "f5864806e3565c34d1b41e716f72609d00b55ea5eac5b924c9719a842ef42206",
+    "wtxid": "80b7d8a82d5d5bf92905b06f2014dd699e03837ca172e3a59d51426ebbe3e7f5",
+    "allowed": true,
+    "vsize": 111,
+    "fees": {
+      "base": 0.00002220,
+      "effective-feerate": 0.00020000,
+      "effective-includes": ["80b7d8a82d5d5bf92905b06f2014dd699e03837ca172e3a59d51426ebbe3e7f5"]
+    }
+  },
+  {
+    "txid": "f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16",
+    "wtxid": "f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16",
+    "allowed": false,
+    "reject-reason": "missing-inputs"
+  }
+]"#;
+
+        let results: Vec<MempoolAcceptResult> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(results.len(), 2);
+
+        let accepted = &results[0];
+        assert_eq!(accepted.allowed, Some(true));
+        assert_eq!(accepted.vsize, Some(111));
+        let fees = accepted.fees.as_ref().unwrap();
+        assert_eq!(fees.base, Amount::from_sat(2220));
+        assert_eq!(
+            fees.effective_fee_rate,
+            Some(FeeRate::from_sat_per_vb_unchecked(20))
+        );
+        assert_eq!(fees.effective_includes, Some(vec![accepted.wtxid]));
+
+        let rejected = &results[1];
+        assert_eq!(rejected.allowed, Some(false));
+        assert_eq!(rejected.fees, None);
+        assert_eq!(rejected.reject_reason.as_deref(), Some("missing-inputs"));
+    }
+
+    #[test]
+    fn test_package_submission_deserialization() {
+        let json = r#"{
+  "package_msg": "success",
+  "tx-results": {
+    "80b7d8a82d5d5bf92905b06f2014dd699e03837ca172e3a59d51426ebbe3e7f5": {
+      "txid": "f5864806e3565c34d1b41e716f72609d00b55ea5eac5b924c9719a842ef42206",
+      "vsize": 111,
+      "fees": {
+        "base": 0.00002220,
+        "effective-feerate": 0.00020000,
+        "effective-includes": ["80b7d8a82d5d5bf92905b06f2014dd699e03837ca172e3a59d51426ebbe3e7f5"]
+      }
+    }
+  },
+  "replaced-transactions": []
+}"#;
+
+        let submission: PackageSubmission = serde_json::from_str(json).unwrap();
+
+        assert!(submission.is_success());
+        assert_eq!(submission.tx_results.len(), 1);
+        assert!(submission.replaced_transactions.is_empty());
+
+        let wtxid =
+            Wtxid::from_str("80b7d8a82d5d5bf92905b06f2014dd699e03837ca172e3a59d51426ebbe3e7f5")
+                .unwrap();
+        let result = submission.tx_results.get(&wtxid).unwrap();
+        assert_eq!(result.vsize, Some(111));
+        assert_eq!(result.error, None);
+        assert_eq!(result.fees.as_ref().unwrap().base, Amount::from_sat(2220));
+    }
+
+    #[test]
+    fn test_block_status_deserialization() {
+        // Genesis block
+        let json = r#"{"in_best_chain":true,"height":0,"next_best":"00000000839a8e6886ab5951d76f411475428afc90947ee320161bbf18eb6048"}"#;
+        let status: BlockStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            status,
+            BlockStatus {
+                in_best_chain: true,
+                height: Some(0),
+                next_best: Some(
+                    BlockHash::from_str(
+                        "00000000839a8e6886ab5951d76f411475428afc90947ee320161bbf18eb6048"
+                    )
+                    .unwrap()
+                ),
+            }
+        );
+
+        // Stale block
+        let json = r#"{"in_best_chain":false}"#;
+        let status: BlockStatus = serde_json::from_str(json).unwrap();
+        assert!(!status.in_best_chain);
+        assert_eq!(status.height, None);
+        assert_eq!(status.next_best, None);
+    }
+
+    #[test]
+    fn test_outspend_deserialization() {
+        // Block 9 coinbase, spent by the first bitcoin transaction
+        let json = r#"[{"spent":true,"txid":"f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16","vin":0,"status":{"confirmed":true,"block_height":170,"block_hash":"00000000d1145790a8694403d4063f323d499e655c83426834d4ce2f8dd4a2ee","block_time":1231731025}}]"#;
+
+        let outspends: Vec<OutspendResponse> = serde_json::from_str(json).unwrap();
+        let outspends: Vec<Option<Outspend>> = outspends
+            .into_iter()
+            .map(OutspendResponse::into_outspend)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            outspends,
+            vec![Some(Outspend {
+                txid: Txid::from_str(
+                    "f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16"
+                )
+                .unwrap(),
+                vin: 0,
+                status: TransactionStatus {
+                    confirmed: true,
+                    block_height: Some(170),
+                    block_hash: Some(
+                        BlockHash::from_str(
+                            "00000000d1145790a8694403d4063f323d499e655c83426834d4ce2f8dd4a2ee"
+                        )
+                        .unwrap()
+                    ),
+                    block_time: Some(1231731025),
+                },
+            })]
+        );
+
+        // Genesis coinbase, unspendable
+        let json = r#"[{"spent":false}]"#;
+        let outspends: Vec<OutspendResponse> = serde_json::from_str(json).unwrap();
+        assert_eq!(outspends[0].into_outspend().unwrap(), None);
+
+        // Spent, but without spending details
+        let json = r#"[{"spent":true}]"#;
+        let outspends: Vec<OutspendResponse> = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            outspends[0].into_outspend(),
+            Err(Error::Mempool(_))
+        ));
+    }
+
+    #[test]
+    fn test_cpfp_info_deserialization() {
+        let json = r#"{
+  "ancestors": [
+    {
+      "txid": "f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16",
+      "fee": 2750,
+      "weight": 1100
+    }
+  ],
+  "descendants": [],
+  "bestDescendant": {
+    "txid": "f5864806e3565c34d1b41e716f72609d00b55ea5eac5b924c9719a842ef42206",
+    "fee": 7735,
+    "weight": 442
+  },
+  "effectiveFeePerVsize": 30,
+  "sigops": 1,
+  "adjustedVsize": 141
+}"#;
+
+        let cpfp: CpfpInfo = serde_json::from_str(json).unwrap();
+
+        assert_eq!(cpfp.ancestors.len(), 1);
+        assert_eq!(cpfp.ancestors[0].fee, Amount::from_sat(2750));
+        assert_eq!(cpfp.ancestors[0].weight, Weight::from_wu(1100));
+        assert!(cpfp.descendants.is_empty());
+        assert_eq!(
+            cpfp.best_descendant.map(|tx| tx.fee),
+            Some(Amount::from_sat(7735))
+        );
+        assert_eq!(
+            cpfp.effective_fee_rate,
+            Some(FeeRate::from_sat_per_vb_unchecked(30))
+        );
+        assert_eq!(cpfp.sigops, Some(1));
+        assert_eq!(cpfp.adjusted_vsize, Some(141.0));
+
+        // Mempool transaction without descendants
+        let json = r#"{"ancestors":[],"bestDescendant":null,"descendants":null,"effectiveFeePerVsize":1.5,"sigops":4,"adjustedVsize":140.25}"#;
+        let cpfp: CpfpInfo = serde_json::from_str(json).unwrap();
+        assert!(cpfp.ancestors.is_empty());
+        assert!(cpfp.descendants.is_empty());
+        assert_eq!(cpfp.best_descendant, None);
+        assert_eq!(
+            cpfp.effective_fee_rate,
+            Some(FeeRate::from_sat_per_kwu(375))
+        );
+        assert_eq!(cpfp.adjusted_vsize, Some(140.25));
+
+        // Confirmed or unknown transaction
+        let json = r#"{"ancestors":[]}"#;
+        let cpfp: CpfpInfo = serde_json::from_str(json).unwrap();
+        assert!(cpfp.ancestors.is_empty());
+        assert!(cpfp.descendants.is_empty());
+        assert_eq!(cpfp.best_descendant, None);
+        assert_eq!(cpfp.effective_fee_rate, None);
+        assert_eq!(cpfp.sigops, None);
+    }
+
+    #[test]
+    fn test_rbf_history_deserialization() {
+        let json = r#"{
+  "replacements": {
+    "tx": {
+      "txid": "f5864806e3565c34d1b41e716f72609d00b55ea5eac5b924c9719a842ef42206",
+      "fee": 2220,
+      "vsize": 111,
+      "value": 506078,
+      "rate": 20,
+      "time": 1713571800,
+      "rbf": true,
+      "fullRbf": false
+    },
+    "time": 1713571800,
+    "fullRbf": false,
+    "replaces": [
+      {
+        "tx": {
+          "txid": "f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16",
+          "fee": 275,
+          "vsize": 275,
+          "value": 5000000000,
+          "rate": 1,
+          "time": 1713571700,
+          "rbf": true
+        },
+        "time": 1713571700,
+        "fullRbf": false,
+        "interval": 100,
+        "replaces": []
+      }
+    ]
+  },
+  "replaces": ["f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16"]
+}"#;
+
+        let history: RbfHistory = serde_json::from_str(json).unwrap();
+
+        let replaced =
+            Txid::from_str("f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16")
+                .unwrap();
+        assert_eq!(history.replaces, Some(vec![replaced]));
+
+        let tree = history.replacements.unwrap();
+        assert_eq!(tree.tx.fee, Amount::from_sat(2220));
+        assert_eq!(tree.tx.rate, FeeRate::from_sat_per_vb_unchecked(20));
+        assert_eq!(tree.tx.full_rbf, Some(false));
+        assert!(!tree.full_rbf);
+        assert_eq!(tree.replaces.len(), 1);
+        assert_eq!(tree.replaces[0].interval, Some(100));
+        assert_eq!(tree.replaced_txids(), vec![replaced]);
+
+        // Never replaced
+        let json = r#"{"replacements":null,"replaces":null}"#;
+        let history: RbfHistory = serde_json::from_str(json).unwrap();
+        assert_eq!(history.replacements, None);
+        assert_eq!(history.replaces, None);
+    }
+
+    #[test]
+    fn test_mining_pool_details_deserialization() {
+        let json = r#"{
+  "pool": {
+    "id": 111,
+    "name": "Foundry USA",
+    "link": "https://foundrydigital.com/",
+    "addresses": ["12KKDt4Mj7N5UAkQMN7LtPZMayenXHa8KL"],
+    "regexes": ["/2cDw/", "Foundry USA Pool"],
+    "slug": "foundryusa",
+    "unique_id": 44
+  },
+  "blockCount": {"all": 68979, "24h": 48, "1w": 311},
+  "blockShare": {"all": 0.0838, "24h": 0.3287, "1w": 0.3084},
+  "estimatedHashrate": 2.5734578929588e20,
+  "reportedHashrate": null,
+  "totalReward": 24378526812345,
+  "avgBlockHealth": 99.21
+}"#;
+
+        let details: MiningPoolDetails = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            details.pool,
+            MiningPool {
+                id: 111,
+                name: "Foundry USA".to_string(),
+                slug: "foundryusa".to_string(),
+                miner_names: None,
+                link: Some("https://foundrydigital.com/".to_string()),
+                addresses: Some(vec!["12KKDt4Mj7N5UAkQMN7LtPZMayenXHa8KL".to_string()]),
+                regexes: Some(vec!["/2cDw/".to_string(), "Foundry USA Pool".to_string()]),
+                unique_id: Some(44),
+            }
+        );
+        assert_eq!(
+            details.block_count,
+            MiningPoolWindows {
+                all: 68979,
+                last_24h: 48,
+                last_week: 311,
+            }
+        );
+        assert_eq!(details.block_share.last_24h, 0.3287);
+        assert_eq!(details.reported_hashrate, None);
+        assert_eq!(details.total_reward, Some(Amount::from_sat(24378526812345)));
+    }
+
+    #[test]
+    fn test_mining_pools_ranking_deserialization() {
+        let json = r#"{"pools":[{"poolId":111,"name":"Foundry USA","link":"https://foundrydigital.com/","blockCount":194,"rank":1,"emptyBlocks":0,"slug":"foundryusa","avgMatchRate":99.8,"avgFeeDelta":"-0.01230000","poolUniqueId":44}],"blockCount":1005,"lastEstimatedHashrate":6.16e20}"#;
+
+        let ranking: MiningPoolsRanking = serde_json::from_str(json).unwrap();
+
+        assert_eq!(ranking.block_count, 1005);
+        assert_eq!(ranking.pools.len(), 1);
+        assert_eq!(ranking.pools[0].slug, "foundryusa");
+        assert_eq!(ranking.pools[0].rank, 1);
+        assert_eq!(ranking.pools[0].avg_match_rate, Some(99.8));
+        assert_eq!(ranking.pools[0].pool_unique_id, Some(44));
+    }
+
+    #[test]
+    fn test_block_fee_rates_deserialization() {
+        let json = r#"[
+  {"avgHeight":840010,"timestamp":1713600000,"avgFee_0":1,"avgFee_10":5,"avgFee_25":8,"avgFee_50":12.5,"avgFee_75":20,"avgFee_90":40,"avgFee_100":900},
+  {"avgHeight":840000,"timestamp":1713500000,"avgFee_0":1,"avgFee_10":4,"avgFee_25":6,"avgFee_50":10,"avgFee_75":15,"avgFee_90":30,"avgFee_100":700}
+]"#;
+
+        let entries: BTreeSet<BlockFeeRatesEntry> = serde_json::from_str(json).unwrap();
+
+        // Ordered by timestamp
+        let heights: Vec<u32> = entries.iter().map(|e| e.avg_height).collect();
+        assert_eq!(heights, vec![840000, 840010]);
+
+        let last = entries.last().unwrap();
+        assert_eq!(last.avg_fee_50, FeeRate::from_sat_per_kwu(3125));
+        assert_eq!(last.avg_fee_100, FeeRate::from_sat_per_vb_unchecked(900));
+    }
+
+    #[test]
+    fn test_block_sizes_and_weights_deserialization() {
+        let json = r#"{"sizes":[{"avgHeight":840000,"timestamp":1713500000,"avgSize":1632547}],"weights":[{"avgHeight":840000,"timestamp":1713500000,"avgWeight":3993241}]}"#;
+
+        let stats: BlockSizesAndWeights = serde_json::from_str(json).unwrap();
+
+        assert_eq!(stats.sizes.first().unwrap().avg_size, 1632547);
+        assert_eq!(
+            stats.weights.first().unwrap().avg_weight,
+            Weight::from_wu(3993241)
+        );
+    }
+
+    #[test]
+    fn test_difficulty_adjustments_deserialization() {
+        let json = r#"[[1713571767,840000,86388558925171.1,1.01145],[1712393606,838656,85412335453957.8,1.03981]]"#;
+
+        let adjustments: Vec<DifficultyAdjustmentResponse> = serde_json::from_str(json).unwrap();
+        let adjustments: BTreeSet<DifficultyEntry> =
+            adjustments.into_iter().map(DifficultyEntry::from).collect();
+
+        let first = adjustments.first().unwrap();
+        assert_eq!(first.timestamp, 1712393606);
+        assert_eq!(first.height, 838656);
+        assert_eq!(first.difficulty, 85412335453957.8);
+        assert_eq!(first.adjustment, Some(1.03981));
+
+        let last = adjustments.last().unwrap();
+        assert_eq!(last.height, 840000);
+    }
+
+    #[test]
+    fn test_block_audit_entries_deserialization() {
+        let json = r#"[{"time":1713571767,"height":840000,"match_rate":99.61},{"time":1712393606,"height":838656,"match_rate":100}]"#;
+
+        let entries: BTreeSet<BlockAuditEntry> = serde_json::from_str(json).unwrap();
+
+        // Ordered by timestamp
+        let first = entries.first().unwrap();
+        assert_eq!(first.timestamp, 1712393606);
+        assert_eq!(first.height, 838656);
+        assert_eq!(first.match_rate, 100.0);
+        assert_eq!(entries.last().unwrap().match_rate, 99.61);
+    }
+
+    #[test]
+    fn test_block_audit_summary_deserialization() {
+        let json = r#"{
+  "height": 840000,
+  "id": "0000000000000000000320283a032748cef8227873ff4872689bf23f1cda83a5",
+  "timestamp": 1713571767,
+  "template": [
+    {
+      "txid": "f5864806e3565c34d1b41e716f72609d00b55ea5eac5b924c9719a842ef42206",
+      "fee": 2220,
+      "vsize": 111,
+      "value": 506078,
+      "rate": 20,
+      "flags": 1099511631881
+    }
+  ],
+  "missingTxs": ["f5864806e3565c34d1b41e716f72609d00b55ea5eac5b924c9719a842ef42206"],
+  "addedTxs": ["f4184fc596403b9d638783cf57adfe4c75c605f6356fbc91338530e9831e9e16"],
+  "freshTxs": [],
+  "sigopTxs": [],
+  "fullrbfTxs": [],
+  "acceleratedTxs": [],
+  "matchRate": 99.5,
+  "expectedFees": 3000000,
+  "expectedWeight": 3991904
+}"#;
+
+        let audit: BlockAuditSummary = serde_json::from_str(json).unwrap();
+
+        assert_eq!(audit.height, 840000);
+        assert_eq!(audit.template.len(), 1);
+        assert_eq!(
+            audit.template[0].rate,
+            FeeRate::from_sat_per_vb_unchecked(20)
+        );
+        assert_eq!(audit.missing_txs, vec![audit.template[0].txid]);
+        assert_eq!(audit.added_txs.len(), 1);
+        assert!(audit.prioritized_txs.is_empty());
+        assert_eq!(audit.match_rate, 99.5);
+        assert_eq!(audit.expected_fees, Some(Amount::from_sat(3000000)));
+        assert_eq!(audit.expected_weight, Some(Weight::from_wu(3991904)));
+    }
+
+    #[test]
+    fn test_historical_prices_deserialization() {
+        let json = r#"{"prices":[{"time":1499904000,"EUR":1964,"USD":2254.9}],"exchangeRates":{"USDEUR":0.92,"USDGBP":0.78,"USDCAD":1.36,"USDCHF":0.86,"USDAUD":1.5,"USDJPY":149.6}}"#;
+
+        let prices: HistoricalPrices = serde_json::from_str(json).unwrap();
+
+        assert_eq!(prices.prices.len(), 1);
+        let price = &prices.prices[0];
+        assert_eq!(price.timestamp, 1499904000);
+        assert_eq!(price.eur(), Some(1964.0));
+        assert_eq!(price.usd(), Some(2254.9));
+        assert_eq!(price.jpy(), None);
+        assert_eq!(prices.exchange_rates.get("USDEUR"), Some(&0.92));
+    }
+
+    #[test]
+    fn test_prices_deserialization() {
+        let json = r#"{"time":1713571767,"USD":64512,"EUR":60521,"GBP":51943,"CAD":88592,"CHF":58683,"AUD":100392,"JPY":9976436,"ARS":0.5}"#;
+
+        let prices: Prices = serde_json::from_str(json).unwrap();
+
+        assert_eq!(prices.timestamp, 1713571767);
+        assert_eq!(prices.usd(), Some(64512.0));
+        assert_eq!(prices.jpy(), Some(9976436.0));
+        assert_eq!(prices.get(&Currency::Other("ARS".to_string())), Some(0.5));
+        assert_eq!(prices.get(&Currency::Other("BRL".to_string())), None);
+
+        // Serialize (known currencies first, then unknown ones)
+        let serialized = serde_json::to_string(&prices).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"time":1713571767,"USD":64512.0,"EUR":60521.0,"GBP":51943.0,"CAD":88592.0,"CHF":58683.0,"AUD":100392.0,"JPY":9976436.0,"ARS":0.5}"#
+        );
+        let deserialized: Prices = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, prices);
+    }
+
+    #[test]
+    fn test_prices_conversion() {
+        let prices: Prices =
+            serde_json::from_str(r#"{"time":1713571767,"USD":64000,"EUR":0,"CHF":-1}"#).unwrap();
+
+        assert_eq!(
+            prices.to_fiat(Amount::from_sat(50_000), &Currency::Usd),
+            Some(32.0)
+        );
+        assert_eq!(
+            prices.to_amount(32.0, &Currency::Usd),
+            Some(Amount::from_sat(50_000))
+        );
+        assert_eq!(
+            prices.to_amount(0.01, &Currency::Usd),
+            Some(Amount::from_sat(16))
+        );
+
+        // Invalid
+        assert_eq!(prices.to_fiat(Amount::ONE_BTC, &Currency::Gbp), None);
+        assert_eq!(prices.to_amount(10.0, &Currency::Eur), None);
+        assert_eq!(prices.to_amount(-1.0, &Currency::Usd), None);
+        assert_eq!(prices.to_fiat(Amount::ONE_BTC, &Currency::Eur), None);
+        assert_eq!(prices.to_fiat(Amount::ONE_BTC, &Currency::Chf), None);
+        assert_eq!(prices.to_amount(10.0, &Currency::Chf), None);
+
+        // Above the supply cap
+        let prices: Prices = serde_json::from_str(r#"{"time":1713571767,"USD":1e-9}"#).unwrap();
+        assert_eq!(prices.to_amount(1.0, &Currency::Usd), None);
+        assert_eq!(prices.to_amount(f64::MAX, &Currency::Usd), None);
+        let prices: Prices = serde_json::from_str(r#"{"time":1713571767,"USD":1}"#).unwrap();
+        assert_eq!(
+            prices.to_amount(21_000_000.0, &Currency::Usd),
+            Some(Amount::MAX_MONEY)
+        );
+        assert_eq!(prices.to_amount(21_000_000.01, &Currency::Usd), None);
+    }
+
     #[test]
     fn test_fee_recommendations_deserialization() {
         let json_data =
@@ -0,0 +1,256 @@
+//! Retry policy
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode};
+
+/// Retry policy
+///
+/// Failed requests are retried with an exponential backoff:
+/// the `n`-th retry waits `initial_backoff * multiplier^(n - 1)`, capped at `max_backoff`,
+/// and reduced by a random fraction (up to `jitter`) to avoid synchronized retries.
+///
+/// The default policy makes up to 3 attempts, retrying timeouts, connection errors
+/// and the `429`, `500`, `502`, `503` and `504` status codes.
+///
+/// Requests submitting transactions ([`MempoolClient::broadcast`], [`MempoolClient::submit_package`], ...)
+/// are retried only on connection errors: after a timeout or a server error, the transaction
+/// may have been accepted anyway, and a retry would report it as already in the mempool.
+///
+/// [`MempoolClient::broadcast`]: crate::client::MempoolClient::broadcast
+/// [`MempoolClient::submit_package`]: crate::client::MempoolClient::submit_package
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Maximum delay between two attempts
+    pub max_backoff: Duration,
+    /// Growth factor of the delay after each retry
+    pub multiplier: f64,
+    /// Maximum fraction of the delay randomly removed (between `0.0` and `1.0`)
+    pub jitter: f64,
+    /// Status codes to retry
+    pub retry_status_codes: Vec<StatusCode>,
+    /// Whether to retry timed out requests
+    pub retry_on_timeout: bool,
+    /// Whether to retry requests that failed to connect
+    pub retry_on_connect: bool,
+    /// Whether to wait for the delay requested by the `Retry-After` header, if any
+    ///
+    /// If the requested delay is longer than `max_backoff`, the request is not retried.
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.5,
+            retry_status_codes: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_on_timeout: true,
+            retry_on_connect: true,
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Set the maximum number of attempts, including the first one
+    #[inline]
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set the backoff curve
+    #[inline]
+    pub fn backoff(mut self, initial: Duration, max: Duration, multiplier: f64) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Set the jitter (between `0.0` and `1.0`)
+    #[inline]
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set the status codes to retry
+    #[inline]
+    pub fn retry_status_codes<I>(mut self, codes: I) -> Self
+    where
+        I: IntoIterator<Item = StatusCode>,
+    {
+        self.retry_status_codes = codes.into_iter().collect();
+        self
+    }
+
+    /// Set whether to retry timed out requests
+    #[inline]
+    pub fn retry_on_timeout(mut self, retry: bool) -> Self {
+        self.retry_on_timeout = retry;
+        self
+    }
+
+    /// Set whether to retry requests that failed to connect
+    #[inline]
+    pub fn retry_on_connect(mut self, retry: bool) -> Self {
+        self.retry_on_connect = retry;
+        self
+    }
+
+    /// Set whether to honour the `Retry-After` header
+    ///
+    /// If the requested delay is longer than `max_backoff`, the request is not retried.
+    #[inline]
+    pub fn respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Whether a request error is worth retrying
+    ///
+    /// Non-idempotent requests are retried only if they failed to connect, so were never received.
+    pub(crate) fn is_retryable_error(&self, error: &reqwest::Error, idempotent: bool) -> bool {
+        (self.retry_on_connect && error.is_connect())
+            || (idempotent && self.retry_on_timeout && error.is_timeout())
+    }
+
+    /// Whether a response status is worth retrying
+    pub(crate) fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.retry_status_codes.contains(&status)
+    }
+
+    /// Delay before the `retry`-th retry (starting from 1), without jitter
+    pub(crate) fn backoff_delay(&self, retry: u32) -> Duration {
+        let exponent: i32 = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let factor: f64 = self.multiplier.max(1.0).powi(exponent);
+        let delay: f64 = self.initial_backoff.as_secs_f64() * factor;
+
+        if delay.is_finite() && delay < self.max_backoff.as_secs_f64() {
+            Duration::from_secs_f64(delay)
+        } else {
+            self.max_backoff
+        }
+    }
+
+    /// Delay before the `retry`-th retry (starting from 1), with jitter
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        let delay: Duration = self.backoff_delay(retry);
+        let jitter: f64 = self.jitter.clamp(0.0, 1.0);
+        delay.mul_f64(1.0 - jitter * random_fraction())
+    }
+
+    /// Delay requested by the `Retry-After` header of a response, if any
+    pub(crate) fn retry_after(&self, response: &Response) -> Option<Duration> {
+        if !self.respect_retry_after {
+            return None;
+        }
+
+        let value: &str = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+        parse_retry_after(value)
+    }
+}
+
+/// Parse a `Retry-After` header value, either in seconds or as an HTTP date
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value: &str = value.trim();
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date: SystemTime = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Random number in `[0, 1)`
+fn random_fraction() -> f64 {
+    // `RandomState` is randomly seeded, which is good enough for jitter
+    let random: u64 = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_delay() {
+        let policy =
+            RetryPolicy::default().backoff(Duration::from_millis(100), Duration::from_secs(1), 2.0);
+
+        assert_eq!(policy.backoff_delay(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff_delay(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff_delay(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff_delay(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff_delay(5), Duration::from_secs(1));
+        assert_eq!(policy.backoff_delay(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_delay_jitter() {
+        let policy = RetryPolicy::default()
+            .backoff(Duration::from_secs(1), Duration::from_secs(10), 2.0)
+            .jitter(0.0);
+        assert_eq!(policy.delay(2), Duration::from_secs(2));
+
+        let policy = policy.jitter(0.5);
+        for _ in 0..100 {
+            let delay: Duration = policy.delay(2);
+            assert!(delay > Duration::from_secs(1));
+            assert!(delay <= Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 0 "), Some(Duration::ZERO));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
+    #[test]
+    fn test_retryable_status() {
+        let policy = RetryPolicy::default();
+        assert!(policy.is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(policy.is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.is_retryable_status(StatusCode::NOT_FOUND));
+
+        let policy = policy.retry_status_codes([StatusCode::NOT_FOUND]);
+        assert!(policy.is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(!policy.is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+    }
+}
@@ -82,7 +82,6 @@ pub(crate) async fn subscribe(
     payload: MempoolSubscriptionRequest,
 ) -> Result<MempoolSubscription, Error> {
     let url: Url = upgrade_scheme_from_http_to_wss(url)?;
-    let url: Url = url.join("/api/v1/ws")?;
 
     let (tx, rx) = mpsc::unbounded_channel();
 
@@ -143,3 +142,26 @@ async fn connect_and_subscribe(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_scheme_keeps_path() {
+        let url = Url::parse("https://infra.example/mempool/api/v1/ws").unwrap();
+        assert_eq!(
+            upgrade_scheme_from_http_to_wss(&url).unwrap().as_str(),
+            "wss://infra.example/mempool/api/v1/ws"
+        );
+
+        let url = Url::parse("http://127.0.0.1:8999/api/v1/ws").unwrap();
+        assert_eq!(
+            upgrade_scheme_from_http_to_wss(&url).unwrap().as_str(),
+            "ws://127.0.0.1:8999/api/v1/ws"
+        );
+
+        let url = Url::parse("ftp://infra.example/api/v1/ws").unwrap();
+        assert!(upgrade_scheme_from_http_to_wss(&url).is_err());
+    }
+}